@@ -52,7 +52,19 @@ async function deploy() {
     console.log("Fee Vault PDA:", feeVaultPda.toString());
   } catch (error) {
     if (error.message.includes("already in use")) {
-      console.log("Config already initialized - deployment complete");
+      console.log("Config already initialized - migrating config accounts");
+
+      // create fee schedule and admin roles missing on configs initialized before them
+      const tx = await program.methods
+        .migrateConfig()
+        .accounts({
+          authority: authority,
+          config: configPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      console.log("Migrate config successful:", tx);
     } else {
       console.error("Initialize failed:", error);
       throw error;
@@ -9,6 +9,34 @@ pub const WILL_SEED: &str = "will";
 pub const VAULT_SEED: &str = "vault";
 #[constant]
 pub const FEE_VAULT_SEED: &str = "fee_vault";
+#[constant]
+pub const VAULT_STAKE_SEED: &str = "vault_stake";
+#[constant]
+pub const WILL_ACTION_SEED: &str = "will_action";
+#[constant]
+pub const PROGRAM_ALLOWLIST_SEED: &str = "program_allowlist";
+#[constant]
+pub const CLAIM_RECORD_SEED: &str = "claim_record";
+#[constant]
+pub const WILL_INVENTORY_SEED: &str = "will_inventory";
+#[constant]
+pub const CONTRIBUTION_SEED: &str = "contribution";
+#[constant]
+pub const PENDING_CONFIG_CHANGE_SEED: &str = "pending_config_change";
+#[constant]
+pub const FEE_SCHEDULE_SEED: &str = "fee_schedule";
+#[constant]
+pub const PENDING_FEE_SCHEDULE_SEED: &str = "pending_fee_schedule";
+#[constant]
+pub const ADMIN_SET_SEED: &str = "admin_set";
+#[constant]
+pub const ADMIN_SIGNER_SEED: &str = "admin_signer";
+#[constant]
+pub const ADMIN_PROPOSAL_SEED: &str = "admin_proposal";
+#[constant]
+pub const ADMIN_ROLES_SEED: &str = "admin_roles";
+#[constant]
+pub const UPGRADE_AUTHORITY_SEED: &str = "upgrade_authority";
 
 /// Date - for parse seconds
 #[constant]
@@ -19,19 +47,81 @@ pub const SECONDS_PER_HOUR: u32 = 60 * 60;
 pub const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
 #[constant]
 pub const SECONDS_PER_WEEK: u32 = 7 * 24 * 60 * 60;
+#[constant]
+pub const SECONDS_PER_YEAR: u32 = 365 * 24 * 60 * 60;
 
 /// Heartbeat
 #[constant]
-pub const MIN_HEARTBEAT_PERIOD: u32 = 1 * SECONDS_PER_DAY; // 1 day
+pub const MIN_HEARTBEAT_PERIOD: u32 = SECONDS_PER_DAY; // 1 day
 
 /// Trigger
 #[constant]
 pub const TRIGGER_GRACE_PERIOD: u32 = 300; // 5 minutes
 
+/// Pause flags - operations that can be paused in config
+#[constant]
+pub const PAUSE_CREATE: u8 = 1 << 0;
+#[constant]
+pub const PAUSE_DEPOSIT: u8 = 1 << 1;
+/// Reserved bit, always rejected by update_config (heartbeats are never paused)
+#[constant]
+pub const PAUSE_HEARTBEAT: u8 = 1 << 2;
+#[constant]
+pub const PAUSE_TRIGGER: u8 = 1 << 3;
+#[constant]
+pub const PAUSE_CLAIM: u8 = 1 << 4;
+/// Reserved bit, always rejected by update_config (withdrawals are never paused)
+#[constant]
+pub const PAUSE_WITHDRAW: u8 = 1 << 5;
+pub const PAUSE_FLAGS_MASK: u8 = (1 << 6) - 1;
+pub const UNPAUSABLE_FLAGS: u8 = PAUSE_HEARTBEAT | PAUSE_WITHDRAW; // reserved bits, never set in config
+pub const PAUSABLE_FLAGS: u8 = PAUSE_FLAGS_MASK & !UNPAUSABLE_FLAGS; // legacy `paused` maps to these
+
+/// Config timelock
+#[constant]
+pub const CONFIG_CHANGE_DELAY: u32 = 7 * SECONDS_PER_DAY; // 7 days
+
 /// Fee
 #[constant]
 pub const NFT_SERVICE_FEE_LAMPORTS: u64 = 1_000_000;
+#[constant]
+pub const MAX_TOKEN_FEE_BPS: u16 = 1_000; // 10%
+#[constant]
+pub const MAX_NFT_FEE_LAMPORTS: u64 = 100_000_000; // 0.1 SOL
+pub const MAX_ANNUAL_FEE_LAMPORTS: u64 = 10_000_000_000; // 10 SOL
+#[constant]
+pub const MAX_REFERRER_SHARE_BPS: u16 = 5_000; // 50% of service fee
+pub const MAX_FEE_TIERS: usize = 4;
+pub const MAX_MINT_FEE_OVERRIDES: usize = 16;
+
+/// Executor
+#[constant]
+pub const MAX_EXECUTOR_FEE_BPS: u16 = 1_000; // 10%
 
 /// NFT
 #[constant]
 pub const NFT_AMOUNT: u32 = 1;
+
+/// Claim all
+#[constant]
+pub const MAX_CLAIM_ALL_ASSETS: u8 = 8;
+pub const CLAIM_ALL_ACCOUNTS_PER_ASSET: usize = 4;
+
+/// Batch deposit
+#[constant]
+pub const MAX_DEPOSIT_BATCH_ASSETS: u8 = 8;
+pub const DEPOSIT_BATCH_ACCOUNTS_PER_ASSET: usize = 3;
+
+/// Inventory
+pub const MAX_INVENTORY_ENTRIES: usize = 64;
+
+/// Will actions
+#[constant]
+pub const MAX_WILL_ACTIONS: u8 = 8;
+pub const MAX_ACTION_ACCOUNTS: usize = 16;
+pub const MAX_ACTION_DATA_LEN: usize = 256;
+pub const MAX_ALLOWED_PROGRAMS: usize = 32;
+
+/// Admin set
+pub const MAX_ADMIN_MEMBERS: usize = 10;
+pub const MAX_ADMIN_ACTION_DATA_LEN: usize = 1024;
@@ -27,6 +27,14 @@ pub enum AppError {
     InvalidMaximumHeartbeatPeriod,
     #[msg("Minimum of heartbeat interval must be greater than zero.")]
     InvalidMinimumHeartbeatInterval,
+    #[msg("Token fee exceeds maximum.")]
+    InvalidTokenFee,
+    #[msg("NFT fee exceeds maximum.")]
+    InvalidNftFee,
+    #[msg("Config change must update at least one field.")]
+    EmptyConfigChange,
+    #[msg("Config change timelock has not elapsed yet.")]
+    ConfigChangeNotReady,
 
     /// Heartbeat errors
     #[msg("Heartbeat period must be greater than zero.")]
@@ -39,6 +47,10 @@ pub enum AppError {
     /// Program errors
     #[msg("Program is paused.")]
     ProgramPaused,
+    #[msg("Unknown pause flags.")]
+    InvalidPauseFlags,
+    #[msg("Heartbeats and withdrawals cannot be paused.")]
+    OperationNotPausable,
 
     /// Will status errors
     #[msg("Invalid will status for this operation.")]
@@ -71,6 +83,14 @@ pub enum AppError {
     NoClaimableAmount,
     #[msg("Will must triggered before claiming")]
     WillNotTriggered,
+    #[msg("Contingent beneficiary cannot claim before claim deadline.")]
+    ClaimDeadlineNotReached,
+    #[msg("Estate already claimed by beneficiary.")]
+    ClaimedByBeneficiary,
+    #[msg("Estate already claimed by contingent beneficiary.")]
+    ClaimedByContingentBeneficiary,
+    #[msg("Destination token account is required.")]
+    MissingDestinationTokenAccount,
 
     /// Withdrawal errors
     #[msg("cannot withdraw after will has been triggered or claimed.")]
@@ -83,4 +103,128 @@ pub enum AppError {
     NoWithdrawableAmount,
     #[msg("Insufficient balance for rent exemption.")]
     InsufficientBalanceForRent,
+
+    /// Stake errors
+    #[msg("Stake account is not initialized.")]
+    InvalidStakeAccount,
+    #[msg("Stake account authorities do not match the expected owner.")]
+    StakeAuthorityMismatch,
+    #[msg("Stake account lockup is still in force.")]
+    StakeLockupInForce,
+    #[msg("Vault SOL is already staked.")]
+    VaultAlreadyStaked,
+    #[msg("Vault SOL is not staked.")]
+    VaultNotStaked,
+    #[msg("Vault SOL is still staked. Deactivate and withdraw stake first.")]
+    VaultStakeOutstanding,
+    #[msg("Stake amount is below the stake account rent exemption.")]
+    StakeAmountTooSmall,
+
+    /// Authority errors
+    #[msg("Authority target is not owned by the expected program.")]
+    InvalidAuthorityTarget,
+    #[msg("Testator does not hold this authority.")]
+    AuthorityNotOwned,
+
+    /// Contingent beneficiary errors
+    #[msg("Contingent beneficiary must differ from testator and beneficiary.")]
+    InvalidContingentBeneficiary,
+    #[msg("Claim deadline must be greater than zero.")]
+    InvalidClaimDeadline,
+
+    /// Unclaimed estate errors
+    #[msg("Unclaimed expiry must be greater than the contingent claim deadline.")]
+    InvalidUnclaimedExpiry,
+    #[msg("Fallback must differ from beneficiary.")]
+    InvalidFallback,
+    #[msg("Estate has not expired unclaimed yet.")]
+    EstateNotExpired,
+
+    /// Payout schedule errors
+    #[msg("Invalid payout schedule.")]
+    InvalidPayoutSchedule,
+    #[msg("Nothing vested to claim yet.")]
+    NothingVested,
+
+    /// Batch errors
+    #[msg("Claim all accounts must be mint, vault, destination and fee vault token accounts.")]
+    InvalidClaimAllAccounts,
+    #[msg("Too many assets for a single claim.")]
+    TooManyClaimAllAssets,
+    #[msg("Batch deposit accounts must be mint, testator and vault token accounts per item.")]
+    InvalidDepositBatchAccounts,
+    #[msg("Too many assets for a single deposit.")]
+    TooManyBatchAssets,
+    #[msg("Payout schedule has not fully vested yet.")]
+    PayoutNotFullyVested,
+
+    /// Inventory errors
+    #[msg("Will inventory is full.")]
+    InventoryFull,
+
+    /// Release date errors
+    #[msg("Release date has not been reached yet.")]
+    ReleaseDateNotReached,
+    #[msg("Release date must be in the future.")]
+    InvalidReleaseDate,
+
+    /// Executor errors
+    #[msg("Executor must differ from testator.")]
+    InvalidExecutor,
+    #[msg("Executor fee exceeds maximum.")]
+    InvalidExecutorFee,
+
+    /// Fee errors
+    #[msg("Current fees are not lower than will fees.")]
+    FeesNotLower,
+    #[msg("Invalid fee schedule.")]
+    InvalidFeeSchedule,
+    #[msg("Subscription fee is not offered.")]
+    SubscriptionUnavailable,
+    #[msg("Will is already on a subscription.")]
+    AlreadySubscribed,
+    #[msg("Subscription renewal is due, send a heartbeat to pay it.")]
+    SubscriptionRenewalDue,
+    #[msg("Referrer must differ from testator and beneficiary.")]
+    InvalidReferrer,
+    #[msg("Referrer account does not match will referrer.")]
+    ReferrerMismatch,
+
+    /// Migration errors
+    #[msg("Account is not a will account.")]
+    InvalidWillAccount,
+    #[msg("Will account is already migrated.")]
+    WillAlreadyMigrated,
+
+    /// Will action errors
+    #[msg("Maximum number of will actions reached.")]
+    TooManyWillActions,
+    #[msg("Will action has too many accounts.")]
+    TooManyActionAccounts,
+    #[msg("Will action data is too long.")]
+    ActionDataTooLong,
+    #[msg("Target program is not allowlisted.")]
+    ProgramNotAllowed,
+    #[msg("Only the vault can sign will actions.")]
+    InvalidActionSigner,
+    #[msg("Remaining accounts do not match will action accounts.")]
+    ActionAccountMismatch,
+    #[msg("Program allowlist is full.")]
+    AllowlistFull,
+
+    /// Admin set errors
+    #[msg("Admin set members must be unique and threshold within member count.")]
+    InvalidAdminSet,
+    #[msg("Signer is not an admin set member.")]
+    NotAdminMember,
+    #[msg("Admin already approved this proposal.")]
+    AlreadyApproved,
+    #[msg("Admin proposal does not have enough approvals.")]
+    ApprovalThresholdNotMet,
+    #[msg("Admin action co-signer did not sign the execution.")]
+    MissingActionCoSigner,
+
+    /// Upgrade errors
+    #[msg("Program upgrade authority is not held by the upgrade authority pda.")]
+    UpgradeAuthorityNotHeld,
 }
@@ -0,0 +1,128 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AddWillAction<'info> {
+    /// Testator adding action
+    #[account(mut)]
+    pub testator: Signer<'info>,
+
+    /// Will account - must be owned by testator
+    #[account(
+        mut,
+        seeds = [
+            WILL_SEED.as_bytes(),
+            testator.key().as_ref(),
+            will.beneficiary.as_ref()
+        ],
+        bump = will.bump,
+        constraint = will.testator == testator.key() @ AppError::Unauthorized,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Will action account
+    #[account(
+        init,
+        payer = testator,
+        space = 8 + WillAction::INIT_SPACE,
+        seeds = [
+            WILL_ACTION_SEED.as_bytes(),
+            will.key().as_ref(),
+            &[will.next_action_index],
+        ],
+        bump,
+    )]
+    pub will_action: Account<'info, WillAction>,
+
+    /// Vault pda (only signer for will actions)
+    #[account(
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Program allowlist for target validation
+    #[account(
+        seeds = [PROGRAM_ALLOWLIST_SEED.as_bytes()],
+        bump = program_allowlist.bump,
+    )]
+    pub program_allowlist: Account<'info, ProgramAllowlist>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddWillAction<'info> {
+    pub fn validate(
+        &self,
+        program_id: Pubkey,
+        accounts: &[ActionAccountMeta],
+        data: &[u8],
+    ) -> Result<()> {
+        // validate will status
+        require!(
+            matches!(self.will.status, WillStatus::Created | WillStatus::Active),
+            AppError::InvalidWillStatus,
+        );
+
+        // validate bounds
+        require!(
+            self.will.action_count < MAX_WILL_ACTIONS,
+            AppError::TooManyWillActions
+        );
+        require!(
+            accounts.len() <= MAX_ACTION_ACCOUNTS,
+            AppError::TooManyActionAccounts
+        );
+        require!(
+            data.len() <= MAX_ACTION_DATA_LEN,
+            AppError::ActionDataTooLong
+        );
+
+        // validate target program
+        require!(
+            program_id != crate::ID && self.program_allowlist.is_allowed(&program_id),
+            AppError::ProgramNotAllowed
+        );
+
+        // validate vault is the only signer
+        let vault = self.vault.key();
+        require!(
+            accounts
+                .iter()
+                .all(|meta| !meta.is_signer || meta.pubkey == vault),
+            AppError::InvalidActionSigner
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handler(
+    ctx: Context<AddWillAction>,
+    program_id: Pubkey,
+    accounts: Vec<ActionAccountMeta>,
+    data: Vec<u8>,
+) -> Result<()> {
+    // validate inputs
+    ctx.accounts.validate(program_id, &accounts, &data)?;
+
+    let will = &mut ctx.accounts.will;
+    let will_action = &mut ctx.accounts.will_action;
+
+    // set will action data
+    will_action.will = will.key();
+    will_action.index = will.next_action_index;
+    will_action.program_id = program_id;
+    will_action.accounts = accounts;
+    will_action.data = data;
+    will_action.bump = ctx.bumps.will_action;
+
+    // update will action counters
+    will.action_count = will.action_count.checked_add(1).ok_or(AppError::Overflow)?;
+    will.next_action_index = will
+        .next_action_index
+        .checked_add(1)
+        .ok_or(AppError::Overflow)?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AdoptCurrentFees<'info> {
+    /// Testator opting into current fees
+    pub testator: Signer<'info>,
+
+    /// Will account - must be owned by testator and not yet triggered
+    #[account(
+        mut,
+        seeds = [
+            WILL_SEED.as_bytes(),
+            testator.key().as_ref(),
+            will.beneficiary.as_ref()
+        ],
+        bump = will.bump,
+        constraint = will.testator == testator.key() @ AppError::Unauthorized,
+        constraint = matches!(will.status, WillStatus::Created | WillStatus::Active) @ AppError::InvalidWillStatus,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Config holding current fees
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> AdoptCurrentFees<'info> {
+    pub fn validate(&self) -> Result<()> {
+        // validate current fees only ever lower the will fees
+        let will_fees = self.will.fees(&self.config);
+        let current_fees = self.config.current_fees();
+        require!(
+            current_fees.token_fee_bps <= will_fees.token_fee_bps
+                && current_fees.nft_fee_lamports <= will_fees.nft_fee_lamports
+                && current_fees != will_fees,
+            AppError::FeesNotLower
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<AdoptCurrentFees>) -> Result<()> {
+    // validations
+    ctx.accounts.validate()?;
+
+    // replace fee snapshot
+    let will = &mut ctx.accounts.will;
+    will.fee_snapshot = Some(ctx.accounts.config.current_fees());
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ApproveAdminAction<'info> {
+    /// Admin set member approving action
+    pub approver: Signer<'info>,
+
+    /// Admin set - approver must be a member
+    #[account(
+        seeds = [ADMIN_SET_SEED.as_bytes()],
+        bump = admin_set.bump,
+        constraint = admin_set.is_member(&approver.key()) @ AppError::NotAdminMember,
+    )]
+    pub admin_set: Account<'info, AdminSet>,
+
+    /// Admin proposal account
+    #[account(
+        mut,
+        seeds = [
+            ADMIN_PROPOSAL_SEED.as_bytes(),
+            &admin_proposal.index.to_le_bytes(),
+        ],
+        bump = admin_proposal.bump,
+        has_one = admin_set,
+    )]
+    pub admin_proposal: Account<'info, AdminProposal>,
+}
+
+pub fn handler(ctx: Context<ApproveAdminAction>) -> Result<()> {
+    let approver = ctx.accounts.approver.key();
+    let admin_set = &ctx.accounts.admin_set;
+    let admin_proposal = &mut ctx.accounts.admin_proposal;
+
+    // validate approve once
+    require!(
+        !admin_proposal.approvals.contains(&approver),
+        AppError::AlreadyApproved
+    );
+
+    // drop approvals of removed members, keeps approvals within allocated space
+    admin_proposal
+        .approvals
+        .retain(|member| admin_set.is_member(member));
+    admin_proposal.approvals.push(approver);
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    /// Proposer or config owner cancelling the change
+    pub authority: Signer<'info>,
+
+    /// The program config account
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+        constraint = config.authority == authority.key() || pending_config_change.proposer == authority.key() @ AppError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Pending config change (closed, rent back to proposer)
+    #[account(
+        mut,
+        seeds = [PENDING_CONFIG_CHANGE_SEED.as_bytes()],
+        bump = pending_config_change.bump,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub pending_config_change: Account<'info, PendingConfigChange>,
+
+    /// Authority that queued the change
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+}
+
+pub fn handler(_ctx: Context<CancelConfigChange>) -> Result<()> {
+    Ok(())
+}
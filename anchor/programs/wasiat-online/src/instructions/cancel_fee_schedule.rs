@@ -0,0 +1,34 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelFeeSchedule<'info> {
+    /// Proposer or config owner cancelling the schedule
+    pub authority: Signer<'info>,
+
+    /// The program config account
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+        constraint = config.authority == authority.key() || pending_fee_schedule.proposer == authority.key() @ AppError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Pending fee schedule (closed, rent back to proposer)
+    #[account(
+        mut,
+        seeds = [PENDING_FEE_SCHEDULE_SEED.as_bytes()],
+        bump = pending_fee_schedule.bump,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub pending_fee_schedule: Account<'info, PendingFeeSchedule>,
+
+    /// Fee manager that queued the schedule
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+}
+
+pub fn handler(_ctx: Context<CancelFeeSchedule>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,390 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::{
+    associated_token::{create_idempotent, get_associated_token_address, AssociatedToken, Create},
+    token::{transfer as token_transfer, Mint, Token, TokenAccount, Transfer as TokenTransfer},
+};
+
+#[derive(Accounts)]
+pub struct ClaimAll<'info> {
+    /// Beneficiary (or contingent beneficiary after claim deadline) claiming whole estate
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    /// Will account - must be triggered with vault stake settled (claimant validated in handler)
+    #[account(
+        mut,
+        constraint = will.status == WillStatus::Triggered || will.status == WillStatus::Claimed @ AppError::InvalidWillStatus,
+        constraint = will.staked_lamports == 0 @ AppError::VaultStakeOutstanding,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// CHECK: destination wallet chosen by beneficiary (beneficiary itself by default)
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// Sol vault pda (also authority for vault token accounts)
+    #[account(
+        mut,
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Config for fee configuration
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        constraint = !config.is_paused(PAUSE_CLAIM) @ AppError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Fee vault for service fees (also authority for fee vault token accounts)
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED.as_bytes()],
+        bump,
+    )]
+    pub fee_vault: SystemAccount<'info>,
+
+    /// Fee schedule (tiers and fee bounds applied to will fee)
+    #[account(
+        seeds = [FEE_SCHEDULE_SEED.as_bytes()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    /// CHECK: will referrer receiving its share of SOL and nft service fees (required if will has one)
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Will inventory (grown for new mints)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    // remaining accounts, per asset:
+    // [mint, vault token account, destination token account, fee vault token account]
+    // (destination token account: destination's ata, created if needed, or another of its token accounts)
+    // followed by the referrer's token account when the will has a referrer
+}
+
+impl<'info> ClaimAll<'info> {
+    pub fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        // validate remaining accounts come in complete groups
+        let accounts_per_asset = self.accounts_per_asset();
+        require!(
+            remaining_accounts
+                .chunks_exact(accounts_per_asset)
+                .remainder()
+                .is_empty(),
+            AppError::InvalidClaimAllAccounts
+        );
+
+        // validate asset count stays within compute limits
+        require!(
+            remaining_accounts.len() / accounts_per_asset <= MAX_CLAIM_ALL_ASSETS as usize,
+            AppError::TooManyClaimAllAssets
+        );
+
+        // validate estate fully vested (partial releases go through claim_sol / claim_spl_token)
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            self.will.is_fully_vested(current_time),
+            AppError::PayoutNotFullyVested
+        );
+
+        Ok(())
+    }
+
+    /// Remaining accounts per asset (referrer token account added when will has a referrer)
+    fn accounts_per_asset(&self) -> usize {
+        CLAIM_ALL_ACCOUNTS_PER_ASSET + self.will.referrer.is_some() as usize
+    }
+
+    /// Will referrer account receiving its share of SOL service fees
+    fn referrer_info(&self) -> Result<AccountInfo<'info>> {
+        let Some(referrer) = self.referrer.as_ref() else {
+            return err!(AppError::ReferrerMismatch);
+        };
+        self.will.validate_referrer(referrer.key())?;
+
+        Ok(referrer.to_account_info())
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimAll<'info>>) -> Result<()> {
+    // validations
+    ctx.accounts.validate(ctx.remaining_accounts)?;
+
+    // validate claimant (contingent beneficiary only after claim deadline)
+    let clock = Clock::get()?;
+    let is_contingent = ctx
+        .accounts
+        .will
+        .claimant_is_contingent(ctx.accounts.beneficiary.key(), clock.unix_timestamp)?;
+
+    // prepare pda signer seeds for vault
+    let will_key = ctx.accounts.will.key();
+    let vault_seeds = &[
+        VAULT_SEED.as_bytes(),
+        will_key.as_ref(),
+        &[ctx.accounts.will.vault_bump],
+    ];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    let fees = ctx.accounts.will.fees(&ctx.accounts.config);
+    let mut claimed_any = false;
+
+    // claim SOL, keeping minimum rent in vault to prevent account closure
+    let min_rent = Rent::get()?.minimum_balance(0);
+    let sol_claimable = ctx.accounts.vault.lamports().saturating_sub(min_rent);
+    if sol_claimable > 0 {
+        let service_fee = ctx.accounts.will.sol_service_fee(
+            &ctx.accounts.config,
+            &ctx.accounts.fee_schedule,
+            sol_claimable,
+        );
+
+        // split service fee with referrer
+        let referrer_fee = ctx.accounts.will.referrer_fee(service_fee);
+        let protocol_fee = service_fee
+            .checked_sub(referrer_fee)
+            .ok_or(AppError::Overflow)?;
+        let final_claimable = sol_claimable
+            .checked_sub(service_fee)
+            .ok_or(AppError::Overflow)?;
+
+        let mut sol_transfers = vec![
+            (protocol_fee, ctx.accounts.fee_vault.to_account_info()),
+            (final_claimable, ctx.accounts.destination.to_account_info()),
+        ];
+        if referrer_fee > 0 {
+            sol_transfers.push((referrer_fee, ctx.accounts.referrer_info()?));
+        }
+
+        for (amount, to) in sol_transfers {
+            if amount > 0 {
+                let transfer_accounts = Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to,
+                };
+
+                let transfer_ctx = CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    transfer_accounts,
+                    vault_signer_seeds,
+                );
+
+                transfer(transfer_ctx, amount)?;
+            }
+        }
+
+        ctx.accounts.will.claimed_so_far = ctx
+            .accounts
+            .will
+            .claimed_so_far
+            .checked_add(sol_claimable)
+            .ok_or(AppError::Overflow)?;
+        WillInventory::record_sol(
+            &mut ctx.accounts.will_inventory,
+            ctx.accounts.beneficiary.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            InventoryChange::Claim(sol_claimable),
+        )?;
+        claimed_any = true;
+    }
+
+    // claim tokens and nfts
+    let mut nft_service_fee: u64 = 0;
+    let accounts_per_asset = ctx.accounts.accounts_per_asset();
+    for asset_accounts in ctx.remaining_accounts.chunks(accounts_per_asset) {
+        let mint_info = &asset_accounts[0];
+        let vault_token_info = &asset_accounts[1];
+        let destination_token_info = &asset_accounts[2];
+        let fee_vault_token_info = &asset_accounts[3];
+
+        // validate vault token account is the vault's ata for mint
+        let mint = Account::<Mint>::try_from(mint_info)?;
+        let vault_token_account = Account::<TokenAccount>::try_from(vault_token_info)?;
+        require_keys_eq!(
+            vault_token_info.key(),
+            get_associated_token_address(&ctx.accounts.vault.key(), &mint.key()),
+            AppError::InvalidClaimAllAccounts
+        );
+
+        let total_amount = vault_token_account.amount;
+        if total_amount == 0 {
+            continue;
+        }
+
+        // nfts pay flat fee in SOL, tokens pay bps in kind
+        let kind = AssetKind::of(&mint);
+        let service_fee = if kind == AssetKind::Nft {
+            nft_service_fee = nft_service_fee
+                .checked_add(fees.nft_fee_lamports)
+                .ok_or(AppError::Overflow)?;
+            0
+        } else {
+            ctx.accounts.will.token_service_fee(
+                &ctx.accounts.config,
+                &ctx.accounts.fee_schedule,
+                &mint.key(),
+                total_amount,
+            )
+        };
+
+        // split service fee with referrer
+        let referrer_fee = ctx.accounts.will.referrer_fee(service_fee);
+        let protocol_fee = service_fee
+            .checked_sub(referrer_fee)
+            .ok_or(AppError::Overflow)?;
+        let claimable_amount = total_amount
+            .checked_sub(service_fee)
+            .ok_or(AppError::Overflow)?;
+
+        // create destination (and fee vault) token accounts if needed
+        let mut token_accounts = vec![(
+            destination_token_info,
+            ctx.accounts.destination.to_account_info(),
+            claimable_amount,
+        )];
+        if protocol_fee > 0 {
+            token_accounts.push((
+                fee_vault_token_info,
+                ctx.accounts.fee_vault.to_account_info(),
+                protocol_fee,
+            ));
+        }
+
+        for (token_info, authority, amount) in token_accounts {
+            if token_info.key() == get_associated_token_address(&authority.key(), &mint.key()) {
+                let create_accounts = Create {
+                    payer: ctx.accounts.beneficiary.to_account_info(),
+                    associated_token: token_info.clone(),
+                    authority,
+                    mint: mint_info.clone(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                };
+
+                let create_ctx = CpiContext::new(
+                    ctx.accounts.associated_token_program.to_account_info(),
+                    create_accounts,
+                );
+
+                create_idempotent(create_ctx)?;
+            } else {
+                // validate other destination token accounts (fee vault must use its ata)
+                let token_account = Account::<TokenAccount>::try_from(token_info)?;
+                require!(
+                    authority.key() == ctx.accounts.destination.key()
+                        && token_account.mint == mint.key()
+                        && token_account.owner == authority.key(),
+                    AppError::InvalidClaimAllAccounts
+                );
+            }
+
+            // transfer from vault
+            let transfer_accounts = TokenTransfer {
+                from: vault_token_info.clone(),
+                to: token_info.clone(),
+                authority: ctx.accounts.vault.to_account_info(),
+            };
+
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_accounts,
+                vault_signer_seeds,
+            );
+
+            token_transfer(transfer_ctx, amount)?;
+        }
+
+        // transfer referrer share to referrer token account
+        if referrer_fee > 0 {
+            let referrer_token_info = &asset_accounts[4];
+            let referrer_token_account = Account::<TokenAccount>::try_from(referrer_token_info)?;
+            require_keys_eq!(
+                referrer_token_account.mint,
+                mint.key(),
+                AppError::InvalidClaimAllAccounts
+            );
+            ctx.accounts
+                .will
+                .validate_referrer(referrer_token_account.owner)?;
+
+            let referrer_transfer_accounts = TokenTransfer {
+                from: vault_token_info.clone(),
+                to: referrer_token_info.clone(),
+                authority: ctx.accounts.vault.to_account_info(),
+            };
+
+            let referrer_transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                referrer_transfer_accounts,
+                vault_signer_seeds,
+            );
+
+            token_transfer(referrer_transfer_ctx, referrer_fee)?;
+        }
+
+        WillInventory::record(
+            &mut ctx.accounts.will_inventory,
+            ctx.accounts.beneficiary.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            mint.key(),
+            kind,
+            InventoryChange::Claim(total_amount),
+        )?;
+        claimed_any = true;
+    }
+
+    require!(claimed_any, AppError::NoAssetsToClaim);
+
+    // split nft service fees with referrer
+    let nft_referrer_fee = ctx.accounts.will.referrer_fee(nft_service_fee);
+    let nft_protocol_fee = nft_service_fee
+        .checked_sub(nft_referrer_fee)
+        .ok_or(AppError::Overflow)?;
+
+    let mut nft_fee_transfers = vec![(nft_protocol_fee, ctx.accounts.fee_vault.to_account_info())];
+    if nft_referrer_fee > 0 {
+        nft_fee_transfers.push((nft_referrer_fee, ctx.accounts.referrer_info()?));
+    }
+
+    // transfer nft service fees from beneficiary to fee vault and referrer
+    for (amount, to) in nft_fee_transfers {
+        if amount > 0 {
+            let fee_transfer_accounts = Transfer {
+                from: ctx.accounts.beneficiary.to_account_info(),
+                to,
+            };
+
+            let fee_transfer_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                fee_transfer_accounts,
+            );
+
+            transfer(fee_transfer_ctx, amount)?;
+        }
+    }
+
+    // update will status
+    let will = &mut ctx.accounts.will;
+    will.status = WillStatus::Claimed;
+    if is_contingent {
+        will.claimed_by_contingent = true;
+    }
+
+    Ok(())
+}
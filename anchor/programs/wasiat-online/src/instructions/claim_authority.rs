@@ -0,0 +1,183 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+#[derive(Accounts)]
+pub struct ClaimAuthority<'info> {
+    /// Beneficiary (or contingent beneficiary after claim deadline) claiming authority
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    /// Will account - must be triggered (claimant validated in handler)
+    #[account(
+        mut,
+        constraint = will.status == WillStatus::Triggered || will.status == WillStatus::Claimed @ AppError::InvalidWillStatus,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// CHECK: new authority chosen by beneficiary (beneficiary itself by default)
+    pub destination: UncheckedAccount<'info>,
+
+    /// CHECK: program data, mint, token account or multisig whose authority is claimed, validated per authority kind
+    #[account(mut)]
+    pub target: UncheckedAccount<'info>,
+
+    /// CHECK: program owning target (upgradeable loader, token program or squads), validated per authority kind
+    pub target_program: UncheckedAccount<'info>,
+
+    /// Vault pda (current authority)
+    #[account(
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Config for fee calculation
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        constraint = !config.is_paused(PAUSE_CLAIM) @ AppError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Fee vault for collection SOL fees (authority fee in sol, same as nft)
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED.as_bytes()],
+        bump,
+    )]
+    pub fee_vault: SystemAccount<'info>,
+
+    /// CHECK: will referrer receiving its share of authority fee (required if will has one)
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Will inventory (grown for new authorities)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimAuthority<'info> {
+    pub fn validate(&self, kind: AuthorityKind) -> Result<()> {
+        // validate vault holds the authority
+        let current_authority = kind.current_authority(&self.target, &self.target_program)?;
+        require!(
+            current_authority == Some(self.vault.key()),
+            AppError::NoAssetsToClaim
+        );
+
+        // validate beneficiary SOL for fee
+        let nft_fee = self.will.fees(&self.config).nft_fee_lamports;
+        if nft_fee > 0 {
+            let beneficiary_balance = self.beneficiary.lamports();
+            let min_rent = Rent::get()?.minimum_balance(0);
+            require!(
+                beneficiary_balance >= nft_fee + min_rent,
+                AppError::InsuffcientBalanceForFees
+            );
+        }
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<ClaimAuthority>, kind: AuthorityKind) -> Result<()> {
+    // validations
+    ctx.accounts.validate(kind)?;
+
+    // validate claimant (contingent beneficiary only after claim deadline)
+    let clock = Clock::get()?;
+    let is_contingent = ctx
+        .accounts
+        .will
+        .claimant_is_contingent(ctx.accounts.beneficiary.key(), clock.unix_timestamp)?;
+
+    // split service fee with referrer
+    let nft_service_fee = ctx
+        .accounts
+        .will
+        .fees(&ctx.accounts.config)
+        .nft_fee_lamports;
+    let referrer_fee = ctx.accounts.will.referrer_fee(nft_service_fee);
+    let protocol_fee = nft_service_fee - referrer_fee;
+
+    // transfer service fee from beneficiary to fee vault
+    if protocol_fee > 0 {
+        let fee_transfer_accounts = Transfer {
+            from: ctx.accounts.beneficiary.to_account_info(),
+            to: ctx.accounts.fee_vault.to_account_info(),
+        };
+
+        let fee_transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            fee_transfer_accounts,
+        );
+
+        transfer(fee_transfer_ctx, protocol_fee)?;
+    }
+
+    // transfer referrer share from beneficiary to referrer
+    if referrer_fee > 0 {
+        let Some(referrer) = ctx.accounts.referrer.as_ref() else {
+            return err!(AppError::ReferrerMismatch);
+        };
+        ctx.accounts.will.validate_referrer(referrer.key())?;
+
+        let referrer_transfer_accounts = Transfer {
+            from: ctx.accounts.beneficiary.to_account_info(),
+            to: referrer.to_account_info(),
+        };
+
+        let referrer_transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            referrer_transfer_accounts,
+        );
+
+        transfer(referrer_transfer_ctx, referrer_fee)?;
+    }
+
+    // prepare pda signer seeds for vault
+    let will_key = ctx.accounts.will.key();
+    let vault_seeds = &[
+        VAULT_SEED.as_bytes(),
+        will_key.as_ref(),
+        &[ctx.accounts.will.vault_bump],
+    ];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    // set authority from vault to destination
+    kind.set_authority(
+        ctx.accounts.target.to_account_info(),
+        ctx.accounts.target_program.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        ctx.accounts.destination.to_account_info(),
+        vault_signer_seeds,
+    )?;
+
+    // update will status
+    let will = &mut ctx.accounts.will;
+    will.status = WillStatus::Claimed;
+    if is_contingent {
+        will.claimed_by_contingent = true;
+    }
+
+    // update inventory
+    WillInventory::record(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.target.key(),
+        AssetKind::Authority,
+        InventoryChange::Claim(1),
+    )?;
+
+    Ok(())
+}
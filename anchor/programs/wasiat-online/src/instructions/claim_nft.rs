@@ -10,15 +10,14 @@ use anchor_spl::{
 
 #[derive(Accounts)]
 pub struct ClaimNft<'info> {
-    /// Beneficiary claiming NFT
+    /// Beneficiary (or contingent beneficiary after claim deadline) claiming NFT
     #[account(mut)]
     pub beneficiary: Signer<'info>,
 
-    /// Will account - must be triggered and beneficiary must match
+    /// Will account - must be triggered (claimant validated in handler)
     #[account(
         mut,
         constraint = will.status == WillStatus::Triggered || will.status == WillStatus::Claimed @ AppError::InvalidWillStatus,
-        constraint = will.beneficiary == beneficiary.key() @ AppError::Unauthorized,
     )]
     pub will: Account<'info, Will>,
 
@@ -38,14 +37,25 @@ pub struct ClaimNft<'info> {
     )]
     pub vault_nft_account: Account<'info, TokenAccount>,
 
-    /// Beneficiary's nft account (destination - create if needed)
+    /// CHECK: destination wallet chosen by beneficiary (beneficiary itself by default)
+    pub destination: UncheckedAccount<'info>,
+
+    /// Destination's associated nft account (create if needed, unless a custom account is given)
     #[account(
         init_if_needed,
         payer = beneficiary,
         associated_token::mint = nft_mint,
-        associated_token::authority = beneficiary
+        associated_token::authority = destination,
     )]
-    pub beneficiary_nft_account: Account<'info, TokenAccount>,
+    pub destination_nft_account: Option<Account<'info, TokenAccount>>,
+
+    /// Custom destination nft account (any token account owned by destination)
+    #[account(
+        mut,
+        token::mint = nft_mint,
+        token::authority = destination,
+    )]
+    pub custom_destination_nft_account: Option<Account<'info, TokenAccount>>,
 
     /// Vault pda (authority for vault_nft_account)
     #[account(
@@ -58,7 +68,7 @@ pub struct ClaimNft<'info> {
     #[account(
         seeds = [CONFIG_SEED.as_bytes()],
         bump,
-        constraint = !config.paused @ AppError::ProgramPaused,
+        constraint = !config.is_paused(PAUSE_CLAIM) @ AppError::ProgramPaused,
     )]
     pub config: Account<'info, Config>,
 
@@ -70,6 +80,18 @@ pub struct ClaimNft<'info> {
     )]
     pub fee_vault: SystemAccount<'info>,
 
+    /// CHECK: will referrer receiving its share of nft fee (required if will has one)
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Will inventory (grown for new mints)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -78,7 +100,7 @@ pub struct ClaimNft<'info> {
 impl<'info> ClaimNft<'info> {
     pub fn validate(&self) -> Result<()> {
         // validate beneficiary SOL for fee
-        let nft_fee = self.config.nft_fee_lamports;
+        let nft_fee = self.will.fees(&self.config).nft_fee_lamports;
         if nft_fee > 0 {
             let beneficiary_balance = self.beneficiary.lamports();
             let mint_rent = Rent::get()?.minimum_balance(0);
@@ -90,12 +112,31 @@ impl<'info> ClaimNft<'info> {
 
         Ok(())
     }
+
+    /// Custom destination nft account if given, else destination's associated nft account
+    pub fn destination_nft_info(&self) -> Result<AccountInfo<'info>> {
+        match (
+            &self.custom_destination_nft_account,
+            &self.destination_nft_account,
+        ) {
+            (Some(nft_account), _) | (None, Some(nft_account)) => Ok(nft_account.to_account_info()),
+            (None, None) => err!(AppError::MissingDestinationTokenAccount),
+        }
+    }
 }
 
 pub fn handler(ctx: Context<ClaimNft>) -> Result<()> {
     // validations
     ctx.accounts.validate()?;
 
+    // validate claimant (contingent beneficiary only after claim deadline)
+    let clock = Clock::get()?;
+    let is_contingent = ctx
+        .accounts
+        .will
+        .claimant_is_contingent(ctx.accounts.beneficiary.key(), clock.unix_timestamp)?;
+
+    let destination_nft_info = ctx.accounts.destination_nft_info()?;
     let will = &mut ctx.accounts.will;
     let config = &ctx.accounts.config;
 
@@ -104,26 +145,50 @@ pub fn handler(ctx: Context<ClaimNft>) -> Result<()> {
     let vault_seeds = &[VAULT_SEED.as_bytes(), will_key.as_ref(), &[will.vault_bump]];
     let vault_signer_seeds = &[&vault_seeds[..]];
 
+    // split service fee with referrer
+    let nft_service_fee = will.fees(config).nft_fee_lamports;
+    let referrer_fee = will.referrer_fee(nft_service_fee);
+    let protocol_fee = nft_service_fee - referrer_fee;
+
     // transfer service fee from beneficiary to fee vault
-    let nft_service_fee = config.nft_fee_lamports;
-    if nft_service_fee > 0 {
+    if protocol_fee > 0 {
         let fee_transfer_account = Transfer {
             from: ctx.accounts.beneficiary.to_account_info(),
             to: ctx.accounts.fee_vault.to_account_info(),
         };
 
         let fee_transfer_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
             fee_transfer_account,
         );
 
-        transfer(fee_transfer_ctx, nft_service_fee)?;
+        transfer(fee_transfer_ctx, protocol_fee)?;
     }
 
-    // transfer nft from vault to beneficiary
+    // transfer referrer share from beneficiary to referrer
+    if referrer_fee > 0 {
+        let Some(referrer) = ctx.accounts.referrer.as_ref() else {
+            return err!(AppError::ReferrerMismatch);
+        };
+        will.validate_referrer(referrer.key())?;
+
+        let referrer_transfer_account = Transfer {
+            from: ctx.accounts.beneficiary.to_account_info(),
+            to: referrer.to_account_info(),
+        };
+
+        let referrer_transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            referrer_transfer_account,
+        );
+
+        transfer(referrer_transfer_ctx, referrer_fee)?;
+    }
+
+    // transfer nft from vault to destination
     let nft_transfer_account = TokenTransfer {
         from: ctx.accounts.vault_nft_account.to_account_info(),
-        to: ctx.accounts.beneficiary_nft_account.to_account_info(),
+        to: destination_nft_info,
         authority: ctx.accounts.vault.to_account_info(),
     };
 
@@ -137,6 +202,19 @@ pub fn handler(ctx: Context<ClaimNft>) -> Result<()> {
 
     // update will status
     will.status = WillStatus::Claimed;
+    if is_contingent {
+        will.claimed_by_contingent = true;
+    }
+
+    // update inventory
+    WillInventory::record(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.nft_mint.key(),
+        AssetKind::Nft,
+        InventoryChange::Claim(NFT_AMOUNT as u64),
+    )?;
 
     Ok(())
 }
@@ -4,18 +4,22 @@ use anchor_lang::system_program::{transfer, Transfer};
 
 #[derive(Accounts)]
 pub struct ClaimSol<'info> {
-    /// Beneficiary claiming SOL
+    /// Beneficiary (or contingent beneficiary after claim deadline) claiming SOL
     #[account(mut)]
     pub beneficiary: Signer<'info>,
 
-    // Will account - must be triggered and beneficiary must match
+    // Will account - must be triggered with vault stake settled (claimant validated in handler)
     #[account(
         mut,
         constraint = will.status == WillStatus::Triggered || will.status == WillStatus::Claimed @ AppError::InvalidWillStatus,
-        constraint = will.beneficiary == beneficiary.key() @ AppError::Unauthorized,
+        constraint = will.staked_lamports == 0 @ AppError::VaultStakeOutstanding,
     )]
     pub will: Account<'info, Will>,
 
+    /// CHECK: destination wallet chosen by beneficiary (beneficiary itself by default)
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
     /// Sol vault pda
     #[account(
         mut,
@@ -28,10 +32,21 @@ pub struct ClaimSol<'info> {
     #[account(
         seeds = [CONFIG_SEED.as_bytes()],
         bump,
-        constraint = !config.paused @ AppError::ProgramPaused,
+        constraint = !config.is_paused(PAUSE_CLAIM) @ AppError::ProgramPaused,
     )]
     pub config: Account<'info, Config>,
 
+    /// Fee schedule (tiers and fee bounds applied to will fee)
+    #[account(
+        seeds = [FEE_SCHEDULE_SEED.as_bytes()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    /// CHECK: will referrer receiving its share of service fee (required if will has one)
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
     /// Fee vault for service fees
     #[account(
         mut,
@@ -40,6 +55,14 @@ pub struct ClaimSol<'info> {
     )]
     pub fee_vault: SystemAccount<'info>,
 
+    /// Will inventory (grown for new mints)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -64,17 +87,33 @@ pub fn handler(ctx: Context<ClaimSol>) -> Result<()> {
     // validation inputs
     ctx.accounts.validate()?;
 
+    // validate claimant (contingent beneficiary only after claim deadline)
+    let clock = Clock::get()?;
+    let is_contingent = ctx
+        .accounts
+        .will
+        .claimant_is_contingent(ctx.accounts.beneficiary.key(), clock.unix_timestamp)?;
+
     let will = &mut ctx.accounts.will;
     let vault_balance = ctx.accounts.vault.lamports();
 
-    // calculate service fee
-    let token_fee_bps = ctx.accounts.config.token_fee_bps;
-    let service_fee = (vault_balance as u128 * token_fee_bps as u128 / 10_000) as u64;
-    let claimable_amount = vault_balance.saturating_sub(service_fee);
-
     // keep minimum rent in vault to prevent account closure
     let min_rent = Rent::get()?.minimum_balance(0);
-    let final_claimable = claimable_amount.saturating_sub(min_rent);
+    let remaining = vault_balance.saturating_sub(min_rent);
+
+    // release only the vested portion
+    let releasable = will.releasable_amount(
+        will.sol_vesting_total,
+        remaining,
+        will.claimed_so_far,
+        clock.unix_timestamp,
+    )?;
+    require!(releasable > 0, AppError::NothingVested);
+
+    // calculate service fee
+    let service_fee =
+        will.sol_service_fee(&ctx.accounts.config, &ctx.accounts.fee_schedule, releasable);
+    let final_claimable = releasable.saturating_sub(service_fee);
 
     require!(final_claimable > 0, AppError::NoClaimableAmount);
 
@@ -83,8 +122,12 @@ pub fn handler(ctx: Context<ClaimSol>) -> Result<()> {
     let vault_seeds = &[VAULT_SEED.as_bytes(), will_key.as_ref(), &[will.vault_bump]];
     let vault_signer_seeds = &[&vault_seeds[..]];
 
+    // split service fee with referrer
+    let referrer_fee = will.referrer_fee(service_fee);
+    let protocol_fee = service_fee - referrer_fee;
+
     // transfer service fee to fee vault
-    if service_fee > 0 {
+    if protocol_fee > 0 {
         let fee_transfer_accounts = Transfer {
             from: ctx.accounts.vault.to_account_info(),
             to: ctx.accounts.fee_vault.to_account_info(),
@@ -96,13 +139,34 @@ pub fn handler(ctx: Context<ClaimSol>) -> Result<()> {
             vault_signer_seeds,
         );
 
-        transfer(fee_transfer_ctx, service_fee)?;
+        transfer(fee_transfer_ctx, protocol_fee)?;
+    }
+
+    // transfer referrer share to referrer
+    if referrer_fee > 0 {
+        let Some(referrer) = ctx.accounts.referrer.as_ref() else {
+            return err!(AppError::ReferrerMismatch);
+        };
+        will.validate_referrer(referrer.key())?;
+
+        let referrer_transfer_accounts = Transfer {
+            from: ctx.accounts.vault.to_account_info(),
+            to: referrer.to_account_info(),
+        };
+
+        let referrer_transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            referrer_transfer_accounts,
+            vault_signer_seeds,
+        );
+
+        transfer(referrer_transfer_ctx, referrer_fee)?;
     }
 
-    // transfer remaining SOL to beneficiary
+    // transfer remaining SOL to destination
     let claim_transfer_accounts = Transfer {
         from: ctx.accounts.vault.to_account_info(),
-        to: ctx.accounts.beneficiary.to_account_info(),
+        to: ctx.accounts.destination.to_account_info(),
     };
 
     let claim_transfer_ctx = CpiContext::new_with_signer(
@@ -114,7 +178,22 @@ pub fn handler(ctx: Context<ClaimSol>) -> Result<()> {
     transfer(claim_transfer_ctx, final_claimable)?;
 
     // update will status
+    will.claimed_so_far = will
+        .claimed_so_far
+        .checked_add(releasable)
+        .ok_or(AppError::Overflow)?;
     will.status = WillStatus::Claimed;
+    if is_contingent {
+        will.claimed_by_contingent = true;
+    }
+
+    // update inventory
+    WillInventory::record_sol(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        InventoryChange::Claim(releasable),
+    )?;
 
     Ok(())
 }
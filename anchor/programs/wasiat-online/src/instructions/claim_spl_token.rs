@@ -7,15 +7,14 @@ use anchor_spl::{
 
 #[derive(Accounts)]
 pub struct ClaimSplToken<'info> {
-    /// Beneficiary claiming spl token
+    /// Beneficiary (or contingent beneficiary after claim deadline) claiming spl token
     #[account(mut)]
     pub beneficiary: Signer<'info>,
 
-    /// Will account - must be triggered and beneficiary must match
+    /// Will account - must be triggered (claimant validated in handler)
     #[account(
         mut,
         constraint = will.status == WillStatus::Triggered || will.status == WillStatus::Claimed @ AppError::InvalidWillStatus,
-        constraint = will.beneficiary == beneficiary.key() @ AppError::Unauthorized,
     )]
     pub will: Account<'info, Will>,
 
@@ -31,14 +30,39 @@ pub struct ClaimSplToken<'info> {
     )]
     pub vault_token_account: Account<'info, TokenAccount>,
 
-    /// Beneficiary's token account (destination - create if needed)
+    /// CHECK: destination wallet chosen by beneficiary (beneficiary itself by default)
+    pub destination: UncheckedAccount<'info>,
+
+    /// Destination's associated token account (create if needed, unless a custom account is given)
     #[account(
         init_if_needed,
         payer = beneficiary,
         associated_token::mint = mint,
-        associated_token::authority = beneficiary,
+        associated_token::authority = destination,
+    )]
+    pub destination_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Custom destination token account (any token account owned by destination)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = destination,
+    )]
+    pub custom_destination_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Claim record tracking vested tokens released for this mint
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        space = 8 + ClaimRecord::INIT_SPACE,
+        seeds = [
+            CLAIM_RECORD_SEED.as_bytes(),
+            will.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
     )]
-    pub beneficiary_token_account: Account<'info, TokenAccount>,
+    pub claim_record: Account<'info, ClaimRecord>,
 
     /// Fee vault's token account (for service fees)
     #[account(
@@ -49,6 +73,13 @@ pub struct ClaimSplToken<'info> {
     )]
     pub fee_vault_token_account: Account<'info, TokenAccount>,
 
+    /// Will referrer's token account receiving its share of service fee (required if will has one)
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub referrer_token_account: Option<Account<'info, TokenAccount>>,
+
     /// Vault pda (authority for vault_token_account)
     #[account(
         seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
@@ -63,35 +94,98 @@ pub struct ClaimSplToken<'info> {
     )]
     pub fee_vault: SystemAccount<'info>,
 
-    /// Config - fee configuration and validate program paused
+    /// Config - fee configuration and validate claims not paused
     #[account(
         seeds = [CONFIG_SEED.as_bytes()],
         bump,
-        constraint = !config.paused @ AppError::ProgramPaused,
+        constraint = !config.is_paused(PAUSE_CLAIM) @ AppError::ProgramPaused,
     )]
     pub config: Account<'info, Config>,
 
+    /// Fee schedule (tiers and fee bounds applied to will fee)
+    #[account(
+        seeds = [FEE_SCHEDULE_SEED.as_bytes()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    /// Will inventory (grown for new mints)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
+impl<'info> ClaimSplToken<'info> {
+    /// Custom destination token account if given, else destination's associated token account
+    pub fn destination_token_info(&self) -> Result<AccountInfo<'info>> {
+        match (
+            &self.custom_destination_token_account,
+            &self.destination_token_account,
+        ) {
+            (Some(token_account), _) | (None, Some(token_account)) => {
+                Ok(token_account.to_account_info())
+            }
+            (None, None) => err!(AppError::MissingDestinationTokenAccount),
+        }
+    }
+}
+
 pub fn handler(ctx: Context<ClaimSplToken>) -> Result<()> {
+    // validate claimant (contingent beneficiary only after claim deadline)
+    let clock = Clock::get()?;
+    let is_contingent = ctx
+        .accounts
+        .will
+        .claimant_is_contingent(ctx.accounts.beneficiary.key(), clock.unix_timestamp)?;
+
+    let destination_token_info = ctx.accounts.destination_token_info()?;
     let will = &mut ctx.accounts.will;
-    let total_amount = ctx.accounts.vault_token_account.amount;
+    let claim_record = &mut ctx.accounts.claim_record;
+    let remaining = ctx.accounts.vault_token_account.amount;
+
+    // snapshot vesting total on first release of this mint
+    if claim_record.vesting_total == 0 {
+        claim_record.vesting_total = remaining;
+    }
+
+    // release only the vested portion
+    let releasable = will.releasable_amount(
+        claim_record.vesting_total,
+        remaining,
+        claim_record.claimed_so_far,
+        clock.unix_timestamp,
+    )?;
+    require!(releasable > 0, AppError::NothingVested);
 
     // calculate service fee
-    let token_fee_bps = ctx.accounts.config.token_fee_bps;
-    let service_fee = (total_amount as u128 * token_fee_bps as u128 / 10_000) as u64;
-    let claimable_amount = total_amount.saturating_sub(service_fee);
+    let service_fee = will.token_service_fee(
+        &ctx.accounts.config,
+        &ctx.accounts.fee_schedule,
+        &ctx.accounts.mint.key(),
+        releasable,
+    );
+    let claimable_amount = releasable.saturating_sub(service_fee);
+
+    require!(claimable_amount > 0, AppError::NoClaimableAmount);
 
     // prepare pda signer seeds
     let will_key = will.key();
     let vault_seeds = &[VAULT_SEED.as_bytes(), will_key.as_ref(), &[will.vault_bump]];
     let vault_signer_seeds = &[&vault_seeds[..]];
 
+    // split service fee with referrer
+    let referrer_fee = will.referrer_fee(service_fee);
+    let protocol_fee = service_fee - referrer_fee;
+
     // transfer service fee to fee vault
-    if service_fee > 0 {
+    if protocol_fee > 0 {
         let fee_transfer_accounts = TokenTransfer {
             from: ctx.accounts.vault_token_account.to_account_info(),
             to: ctx.accounts.fee_vault_token_account.to_account_info(),
@@ -104,13 +198,35 @@ pub fn handler(ctx: Context<ClaimSplToken>) -> Result<()> {
             vault_signer_seeds,
         );
 
-        token_transfer(fee_transfer_ctx, service_fee)?;
+        token_transfer(fee_transfer_ctx, protocol_fee)?;
     }
 
-    // transfer tokens to beneficiary
+    // transfer referrer share to referrer token account
+    if referrer_fee > 0 {
+        let Some(referrer_token_account) = ctx.accounts.referrer_token_account.as_ref() else {
+            return err!(AppError::ReferrerMismatch);
+        };
+        will.validate_referrer(referrer_token_account.owner)?;
+
+        let referrer_transfer_accounts = TokenTransfer {
+            from: ctx.accounts.vault_token_account.to_account_info(),
+            to: referrer_token_account.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+        };
+
+        let referrer_transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            referrer_transfer_accounts,
+            vault_signer_seeds,
+        );
+
+        token_transfer(referrer_transfer_ctx, referrer_fee)?;
+    }
+
+    // transfer tokens to destination
     let claim_transfer_accounts = TokenTransfer {
         from: ctx.accounts.vault_token_account.to_account_info(),
-        to: ctx.accounts.beneficiary_token_account.to_account_info(),
+        to: destination_token_info,
         authority: ctx.accounts.vault.to_account_info(),
     };
 
@@ -122,8 +238,30 @@ pub fn handler(ctx: Context<ClaimSplToken>) -> Result<()> {
 
     token_transfer(claim_transfer_ctx, claimable_amount)?;
 
+    // update claim record
+    claim_record.will = will_key;
+    claim_record.mint = ctx.accounts.mint.key();
+    claim_record.claimed_so_far = claim_record
+        .claimed_so_far
+        .checked_add(releasable)
+        .ok_or(AppError::Overflow)?;
+    claim_record.bump = ctx.bumps.claim_record;
+
     // update will status
     will.status = WillStatus::Claimed;
+    if is_contingent {
+        will.claimed_by_contingent = true;
+    }
+
+    // update inventory
+    WillInventory::record(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.mint.key(),
+        AssetKind::of(&ctx.accounts.mint),
+        InventoryChange::Claim(releasable),
+    )?;
 
     Ok(())
 }
@@ -0,0 +1,217 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::{
+    prelude::*,
+    solana_program::stake::state::StakeAuthorize,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::stake::{authorize, Authorize, Stake, StakeAccount};
+
+#[derive(Accounts)]
+pub struct ClaimStake<'info> {
+    /// Beneficiary (or contingent beneficiary after claim deadline) claiming stake account
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    /// Will account - must be triggered (claimant validated in handler)
+    #[account(
+        mut,
+        constraint = will.status == WillStatus::Triggered || will.status == WillStatus::Claimed @ AppError::InvalidWillStatus,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// CHECK: new staker and withdrawer chosen by beneficiary (beneficiary itself by default)
+    pub destination: UncheckedAccount<'info>,
+
+    /// Native stake account (staker and withdrawer must be vault)
+    #[account(mut)]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// Vault pda (current staker and withdrawer authority)
+    #[account(
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Config for fee calculation
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        constraint = !config.is_paused(PAUSE_CLAIM) @ AppError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Fee schedule (tiers and fee bounds applied to will fee)
+    #[account(
+        seeds = [FEE_SCHEDULE_SEED.as_bytes()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    /// Fee vault for collection SOL fees (stake fee in sol)
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED.as_bytes()],
+        bump,
+    )]
+    pub fee_vault: SystemAccount<'info>,
+
+    /// CHECK: will referrer receiving its share of service fee (required if will has one)
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Will inventory (grown for new stake accounts)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub stake_program: Program<'info, Stake>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimStake<'info> {
+    pub fn validate(&self) -> Result<()> {
+        // validate deposited stake account, vault stake settles through withdraw_vault_stake
+        let (vault_stake, _) = Pubkey::find_program_address(
+            &[VAULT_STAKE_SEED.as_bytes(), self.will.key().as_ref()],
+            &crate::ID,
+        );
+        require!(
+            self.stake_account.key() != vault_stake,
+            AppError::InvalidStakeAccount
+        );
+
+        // validate vault holds both authorities
+        let authorized = self
+            .stake_account
+            .authorized()
+            .ok_or(AppError::InvalidStakeAccount)?;
+        let vault = self.vault.key();
+        require!(
+            authorized.staker == vault && authorized.withdrawer == vault,
+            AppError::NoAssetsToClaim
+        );
+
+        // validate beneficiary SOL for fee
+        let service_fee = self.service_fee();
+        if service_fee > 0 {
+            let beneficiary_balance = self.beneficiary.lamports();
+            let min_rent = Rent::get()?.minimum_balance(0);
+            require!(
+                beneficiary_balance >= service_fee + min_rent,
+                AppError::InsuffcientBalanceForFees
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Service fee in lamports, charged on the stake account balance
+    pub fn service_fee(&self) -> u64 {
+        let stake_balance = self.stake_account.to_account_info().lamports();
+        self.will
+            .sol_service_fee(&self.config, &self.fee_schedule, stake_balance)
+    }
+}
+
+pub fn handler(ctx: Context<ClaimStake>) -> Result<()> {
+    // validations
+    ctx.accounts.validate()?;
+
+    // validate claimant (contingent beneficiary only after claim deadline)
+    let clock = Clock::get()?;
+    let is_contingent = ctx
+        .accounts
+        .will
+        .claimant_is_contingent(ctx.accounts.beneficiary.key(), clock.unix_timestamp)?;
+
+    // split service fee with referrer
+    let service_fee = ctx.accounts.service_fee();
+    let referrer_fee = ctx.accounts.will.referrer_fee(service_fee);
+    let protocol_fee = service_fee - referrer_fee;
+
+    // transfer service fee from beneficiary to fee vault (stake stays delegated)
+    if protocol_fee > 0 {
+        let fee_transfer_accounts = Transfer {
+            from: ctx.accounts.beneficiary.to_account_info(),
+            to: ctx.accounts.fee_vault.to_account_info(),
+        };
+
+        let fee_transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            fee_transfer_accounts,
+        );
+
+        transfer(fee_transfer_ctx, protocol_fee)?;
+    }
+
+    // transfer referrer share from beneficiary to referrer
+    if referrer_fee > 0 {
+        let Some(referrer) = ctx.accounts.referrer.as_ref() else {
+            return err!(AppError::ReferrerMismatch);
+        };
+        ctx.accounts.will.validate_referrer(referrer.key())?;
+
+        let referrer_transfer_accounts = Transfer {
+            from: ctx.accounts.beneficiary.to_account_info(),
+            to: referrer.to_account_info(),
+        };
+
+        let referrer_transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            referrer_transfer_accounts,
+        );
+
+        transfer(referrer_transfer_ctx, referrer_fee)?;
+    }
+
+    // prepare pda signer seeds for vault
+    let will_key = ctx.accounts.will.key();
+    let vault_seeds = &[
+        VAULT_SEED.as_bytes(),
+        will_key.as_ref(),
+        &[ctx.accounts.will.vault_bump],
+    ];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    // reassign staker and withdrawer authorities from vault to destination
+    for stake_authorize in [StakeAuthorize::Staker, StakeAuthorize::Withdrawer] {
+        let authorize_accounts = Authorize {
+            stake: ctx.accounts.stake_account.to_account_info(),
+            authorized: ctx.accounts.vault.to_account_info(),
+            new_authorized: ctx.accounts.destination.to_account_info(),
+            clock: ctx.accounts.clock.to_account_info(),
+        };
+
+        let authorize_ctx = CpiContext::new_with_signer(
+            ctx.accounts.stake_program.to_account_info(),
+            authorize_accounts,
+            vault_signer_seeds,
+        );
+
+        authorize(authorize_ctx, stake_authorize, None)?;
+    }
+
+    // update will status
+    let will = &mut ctx.accounts.will;
+    will.status = WillStatus::Claimed;
+    if is_contingent {
+        will.claimed_by_contingent = true;
+    }
+
+    // update inventory
+    WillInventory::record(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.stake_account.key(),
+        AssetKind::Stake,
+        InventoryChange::Claim(ctx.accounts.stake_account.to_account_info().lamports()),
+    )?;
+
+    Ok(())
+}
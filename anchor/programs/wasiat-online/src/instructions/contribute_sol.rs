@@ -0,0 +1,130 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::spl_token::native_mint;
+
+#[derive(Accounts)]
+pub struct ContributeSol<'info> {
+    /// Anyone contributing SOL (gains no rights over will)
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    /// Will account - must be active
+    #[account(
+        mut,
+        constraint = will.status == WillStatus::Active @ AppError::InvalidWillStatus,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Config - validate deposits not paused
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        constraint = !config.is_paused(PAUSE_DEPOSIT) @ AppError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Sol vault pda
+    #[account(
+        mut,
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Contribution record for contributor attribution
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = 8 + Contribution::INIT_SPACE,
+        seeds = [
+            CONTRIBUTION_SEED.as_bytes(),
+            will.key().as_ref(),
+            contributor.key().as_ref(),
+            native_mint::ID.as_ref(),
+        ],
+        bump,
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    /// Will inventory (grown for new mints)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ContributeSol<'info> {
+    pub fn validate(&self, amount: u64) -> Result<()> {
+        // validate amount
+        require!(amount > 0, AppError::InvalidAmount);
+
+        // validate will not expired awaiting trigger
+        let current_time = Clock::get()?.unix_timestamp;
+        let paused_seconds = self.config.paused_seconds(current_time);
+        require!(
+            current_time < self.will.expiry_time(paused_seconds),
+            AppError::WillAlreadyExpired
+        );
+
+        // validate contributor balance (plus rent exemption)
+        let min_rent = Rent::get()?.minimum_balance(0);
+        require!(
+            self.contributor.lamports() >= amount + min_rent,
+            AppError::InsufficientBalance
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<ContributeSol>, amount: u64) -> Result<()> {
+    // validate inputs
+    ctx.accounts.validate(amount)?;
+
+    // transfer SOL from contributor to vault
+    let transfer_accounts = Transfer {
+        from: ctx.accounts.contributor.to_account_info(),
+        to: ctx.accounts.vault.to_account_info(),
+    };
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        transfer_accounts,
+    );
+
+    transfer(transfer_ctx, amount)?;
+
+    // record contribution
+    let contribution = &mut ctx.accounts.contribution;
+    contribution.will = ctx.accounts.will.key();
+    contribution.contributor = ctx.accounts.contributor.key();
+    contribution.mint = native_mint::ID;
+    contribution.kind = AssetKind::Fungible;
+    contribution.amount = contribution
+        .amount
+        .checked_add(amount)
+        .ok_or(AppError::Overflow)?;
+    contribution.bump = ctx.bumps.contribution;
+
+    // keep contributed SOL out of testator withdrawals
+    let will = &mut ctx.accounts.will;
+    will.contributed_lamports = will
+        .contributed_lamports
+        .checked_add(amount)
+        .ok_or(AppError::Overflow)?;
+
+    // update inventory
+    WillInventory::record_sol(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.contributor.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        InventoryChange::Contribute(amount),
+    )?;
+
+    Ok(())
+}
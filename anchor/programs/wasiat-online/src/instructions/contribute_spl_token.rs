@@ -0,0 +1,146 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer as token_transfer, Mint, Token, TokenAccount, Transfer as TokenTransfer},
+};
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct ContributeSplToken<'info> {
+    /// Anyone contributing tokens (gains no rights over will)
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    /// Will account - must be active
+    #[account(
+        constraint = will.status == WillStatus::Active @ AppError::InvalidWillStatus,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Config - validate deposits not paused
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        constraint = !config.is_paused(PAUSE_DEPOSIT) @ AppError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Token mint
+    pub mint: Account<'info, Mint>,
+
+    /// Contributor's token account (source)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = contributor,
+        constraint = contributor_token_account.amount >= amount @ AppError::InsufficientBalance,
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+
+    /// Vault's token account (destination - create if needed)
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Vault pda (authority for vault_token_account)
+    #[account(
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Contribution record for contributor attribution
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = 8 + Contribution::INIT_SPACE,
+        seeds = [
+            CONTRIBUTION_SEED.as_bytes(),
+            will.key().as_ref(),
+            contributor.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    /// Will inventory (grown for new mints)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ContributeSplToken<'info> {
+    pub fn validate(&self, amount: u64) -> Result<()> {
+        // validate amount
+        require!(amount > 0, AppError::InvalidAmount);
+
+        // validate will not expired awaiting trigger
+        let current_time = Clock::get()?.unix_timestamp;
+        let paused_seconds = self.config.paused_seconds(current_time);
+        require!(
+            current_time < self.will.expiry_time(paused_seconds),
+            AppError::WillAlreadyExpired
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<ContributeSplToken>, amount: u64) -> Result<()> {
+    // validate inputs
+    ctx.accounts.validate(amount)?;
+
+    // transfer spl token from contributor to vault
+    let token_transfer_accounts = TokenTransfer {
+        from: ctx.accounts.contributor_token_account.to_account_info(),
+        to: ctx.accounts.vault_token_account.to_account_info(),
+        authority: ctx.accounts.contributor.to_account_info(),
+    };
+
+    let token_transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        token_transfer_accounts,
+    );
+
+    token_transfer(token_transfer_ctx, amount)?;
+
+    // record contribution
+    let contribution = &mut ctx.accounts.contribution;
+    contribution.will = ctx.accounts.will.key();
+    contribution.contributor = ctx.accounts.contributor.key();
+    contribution.mint = ctx.accounts.mint.key();
+    if contribution.amount == 0 {
+        contribution.kind = AssetKind::of(&ctx.accounts.mint);
+    }
+    contribution.amount = contribution
+        .amount
+        .checked_add(amount)
+        .ok_or(AppError::Overflow)?;
+    contribution.bump = ctx.bumps.contribution;
+    let kind = contribution.kind;
+
+    // update inventory (same entry for every contribution of this mint)
+    WillInventory::record(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.contributor.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.mint.key(),
+        kind,
+        InventoryChange::Contribute(amount),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateAdminSet<'info> {
+    /// Config authority creating the admin set
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Config account for authority validation
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ AppError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Admin set account
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AdminSet::INIT_SPACE,
+        seeds = [ADMIN_SET_SEED.as_bytes()],
+        bump,
+    )]
+    pub admin_set: Account<'info, AdminSet>,
+
+    /// Admin signer pda (becomes config authority via set_config_authority)
+    #[account(
+        seeds = [ADMIN_SIGNER_SEED.as_bytes()],
+        bump,
+    )]
+    pub admin_signer: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateAdminSet>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    // validate inputs
+    AdminSet::validate_members(&members, threshold)?;
+
+    // set admin set data
+    let admin_set = &mut ctx.accounts.admin_set;
+    admin_set.members = members;
+    admin_set.threshold = threshold;
+    admin_set.proposal_count = 0;
+    admin_set.bump = ctx.bumps.admin_set;
+    admin_set.signer_bump = ctx.bumps.admin_signer;
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use crate::{constants::*, error::AppError, state::*};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
-#[instruction(beneficiary: Pubkey, heartbeat_period: u32)]
+#[instruction(beneficiary: Pubkey, heartbeat_period: u32, referrer: Option<Pubkey>)]
 pub struct CreateWill<'info> {
     /// User creating will
     #[account(mut)]
@@ -37,11 +37,33 @@ pub struct CreateWill<'info> {
     )]
     pub vault: SystemAccount<'info>,
 
+    /// Fee schedule (sets referrer share)
+    #[account(
+        seeds = [FEE_SCHEDULE_SEED.as_bytes()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    /// Will inventory listing deposited mints
+    #[account(
+        init,
+        payer = testator,
+        space = WillInventory::space(0),
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> CreateWill<'info> {
-    pub fn validate(&self, heartbeat_period: u32) -> Result<()> {
+    pub fn validate(
+        &self,
+        beneficiary: Pubkey,
+        heartbeat_period: u32,
+        referrer: Option<Pubkey>,
+    ) -> Result<()> {
         let config = &self.config;
 
         // validate heartbeart period range
@@ -50,16 +72,36 @@ impl<'info> CreateWill<'info> {
             AppError::HeartbeatPeriodTooShort
         );
 
-        // program not paused
-        require!(!config.paused, AppError::ProgramPaused);
+        // will creation not paused
+        require!(!config.is_paused(PAUSE_CREATE), AppError::ProgramPaused);
+
+        // validate referrer is a third party
+        if let Some(referrer) = referrer {
+            require!(
+                referrer != self.testator.key() && referrer != beneficiary,
+                AppError::InvalidReferrer
+            );
+        }
 
         Ok(())
     }
 }
 
-pub fn handler(ctx: Context<CreateWill>, beneficiary: Pubkey, heartbeat_period: u32) -> Result<()> {
+pub fn handler(
+    ctx: Context<CreateWill>,
+    beneficiary: Pubkey,
+    heartbeat_period: u32,
+    referrer: Option<Pubkey>,
+) -> Result<()> {
     // validate inputs
-    ctx.accounts.validate(heartbeat_period)?;
+    ctx.accounts
+        .validate(beneficiary, heartbeat_period, referrer)?;
+
+    // referrer share fixed at creation
+    let referrer_share_bps = match referrer {
+        Some(_) => ctx.accounts.fee_schedule.referrer_share_bps,
+        None => 0,
+    };
 
     let will = &mut ctx.accounts.will;
     let clock = Clock::get()?;
@@ -72,10 +114,37 @@ pub fn handler(ctx: Context<CreateWill>, beneficiary: Pubkey, heartbeat_period:
     will.status = WillStatus::Created;
     will.created_at = clock.unix_timestamp;
     will.last_heartbeat = clock.unix_timestamp;
+    will.paused_seconds_at_heartbeat = ctx.accounts.config.paused_seconds(clock.unix_timestamp);
     will.trigger_at = None;
     will.bump = ctx.bumps.will;
     will.vault_bump = ctx.bumps.vault;
-    will.reserved = [0; 64];
+    will.staked_lamports = 0;
+    will.action_count = 0;
+    will.next_action_index = 0;
+    will.contingent_beneficiary = None;
+    will.claim_deadline = 0;
+    will.claimed_by_contingent = false;
+    will.fallback = None;
+    will.unclaimed_expiry = 0;
+    will.payout_schedule = PayoutSchedule::Immediate;
+    will.claimed_so_far = 0;
+    will.sol_vesting_total = 0;
+    will.release_not_before = None;
+    will.executor = None;
+    will.executor_fee_bps = 0;
+    will.fee_snapshot = Some(ctx.accounts.config.current_fees());
+    will.subscription_fee_lamports = 0;
+    will.subscription_paid_until = 0;
+    will.referrer = referrer;
+    will.referrer_share_bps = referrer_share_bps;
+    will.contributed_lamports = 0;
+    will.reserved = [0; 88];
+
+    // set empty inventory
+    let will_inventory = &mut ctx.accounts.will_inventory;
+    will_inventory.will = will.key();
+    will_inventory.bump = ctx.bumps.will_inventory;
+    will_inventory.entries = Vec::new();
 
     Ok(())
 }
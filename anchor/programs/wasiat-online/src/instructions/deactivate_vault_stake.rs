@@ -0,0 +1,86 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::stake::{deactivate_stake, DeactivateStake, Stake, StakeAccount};
+
+#[derive(Accounts)]
+pub struct DeactivateVaultStake<'info> {
+    /// Testator before trigger, claimant after trigger, anyone after expiry
+    pub authority: Signer<'info>,
+
+    /// Will account owning the vault stake
+    #[account(
+        constraint = will.staked_lamports > 0 @ AppError::VaultNotStaked,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Vault pda (staker authority)
+    #[account(
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Vault stake account pda
+    #[account(
+        mut,
+        seeds = [VAULT_STAKE_SEED.as_bytes(), will.key().as_ref()],
+        bump,
+    )]
+    pub vault_stake: Account<'info, StakeAccount>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub stake_program: Program<'info, Stake>,
+}
+
+impl<'info> DeactivateVaultStake<'info> {
+    pub fn validate(&self) -> Result<()> {
+        let will = &self.will;
+        let authority = self.authority.key();
+
+        // validate signer for current will status
+        match will.status {
+            WillStatus::Created | WillStatus::Active => {
+                require!(authority == will.testator, AppError::Unauthorized)
+            }
+            WillStatus::Triggered | WillStatus::Claimed => {
+                let current_time = Clock::get()?.unix_timestamp;
+                will.claimant_is_contingent(authority, current_time)?;
+            }
+            WillStatus::Expired => {} // anyone, stake returns to vault for reclaim
+            WillStatus::Withdrawn => return err!(AppError::InvalidWillStatus),
+        }
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<DeactivateVaultStake>) -> Result<()> {
+    // validations
+    ctx.accounts.validate()?;
+
+    // prepare pda signer seeds for vault
+    let will_key = ctx.accounts.will.key();
+    let vault_seeds = &[
+        VAULT_SEED.as_bytes(),
+        will_key.as_ref(),
+        &[ctx.accounts.will.vault_bump],
+    ];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    // deactivate vault stake, lamports withdrawable after cooldown
+    let deactivate_accounts = DeactivateStake {
+        stake: ctx.accounts.vault_stake.to_account_info(),
+        staker: ctx.accounts.vault.to_account_info(),
+        clock: ctx.accounts.clock.to_account_info(),
+    };
+
+    let deactivate_ctx = CpiContext::new_with_signer(
+        ctx.accounts.stake_program.to_account_info(),
+        deactivate_accounts,
+        vault_signer_seeds,
+    );
+
+    deactivate_stake(deactivate_ctx)?;
+
+    Ok(())
+}
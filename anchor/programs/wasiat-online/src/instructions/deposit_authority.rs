@@ -0,0 +1,108 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct DepositAuthority<'info> {
+    /// Testator handing over authority
+    #[account(mut)]
+    pub testator: Signer<'info>,
+
+    /// Will account - must be owned by testator
+    #[account(
+        mut,
+        seeds = [
+            WILL_SEED.as_bytes(),
+            testator.key().as_ref(),
+            will.beneficiary.as_ref()
+        ],
+        bump = will.bump,
+        constraint = will.testator == testator.key() @ AppError::Unauthorized,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Config for heartbeat interval and validate deposits not paused
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        constraint = !config.is_paused(PAUSE_DEPOSIT) @ AppError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: program data, mint, token account or multisig whose authority is handed over, validated per authority kind
+    #[account(mut)]
+    pub target: UncheckedAccount<'info>,
+
+    /// CHECK: program owning target (upgradeable loader, token program or squads), validated per authority kind
+    pub target_program: UncheckedAccount<'info>,
+
+    /// Vault pda (new authority)
+    #[account(
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Will inventory (grown for new authorities)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositAuthority<'info> {
+    pub fn validate(&self, kind: AuthorityKind) -> Result<()> {
+        // validate will status
+        require!(
+            matches!(self.will.status, WillStatus::Created | WillStatus::Active),
+            AppError::InvalidWillStatus,
+        );
+
+        // validate testator holds the authority
+        let current_authority = kind.current_authority(&self.target, &self.target_program)?;
+        require!(
+            current_authority == Some(self.testator.key()),
+            AppError::AuthorityNotOwned
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handler(
+    ctx: Context<DepositAuthority>,
+    kind: AuthorityKind,
+    as_heartbeat: bool,
+) -> Result<()> {
+    // validate inputs
+    ctx.accounts.validate(kind)?;
+
+    // set authority from testator to vault
+    kind.set_authority(
+        ctx.accounts.target.to_account_info(),
+        ctx.accounts.target_program.to_account_info(),
+        ctx.accounts.testator.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        &[],
+    )?;
+
+    // update inventory
+    WillInventory::record(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.testator.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.target.key(),
+        AssetKind::Authority,
+        InventoryChange::Deposit(1),
+    )?;
+
+    // activate will on first deposit, refresh heartbeat only if requested
+    let clock = Clock::get()?;
+    let will = &mut ctx.accounts.will;
+    will.apply_deposit(as_heartbeat, &ctx.accounts.config, clock.unix_timestamp)?;
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{create_idempotent, get_associated_token_address, AssociatedToken, Create},
+    token::{transfer as token_transfer, Mint, Token, TokenAccount, Transfer as TokenTransfer},
+};
+
+/// Single asset in a batch deposit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct DepositItem {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct DepositBatch<'info> {
+    /// Testator depositing tokens and nfts
+    #[account(mut)]
+    pub testator: Signer<'info>,
+
+    /// Will account - must be owned by testator
+    #[account(
+        mut,
+        seeds = [
+            WILL_SEED.as_bytes(),
+            testator.key().as_ref(),
+            will.beneficiary.as_ref()
+        ],
+        bump = will.bump,
+        constraint = will.testator == testator.key() @ AppError::Unauthorized,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Config for heartbeat interval and validate deposits not paused
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        constraint = !config.is_paused(PAUSE_DEPOSIT) @ AppError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Vault pda (authority for vault token accounts)
+    #[account(
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Will inventory (grown for new mints)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    // remaining accounts, per item in order:
+    // [mint, testator token account, vault token account]
+}
+
+impl<'info> DepositBatch<'info> {
+    pub fn validate(
+        &self,
+        items: &[DepositItem],
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        // validate will status
+        require!(
+            matches!(self.will.status, WillStatus::Created | WillStatus::Active),
+            AppError::InvalidWillStatus,
+        );
+
+        // validate batch size
+        require!(!items.is_empty(), AppError::InvalidAmount);
+        require!(
+            items.len() <= MAX_DEPOSIT_BATCH_ASSETS as usize,
+            AppError::TooManyBatchAssets
+        );
+
+        // validate one account group per item
+        require!(
+            remaining_accounts.len() == items.len() * DEPOSIT_BATCH_ACCOUNTS_PER_ASSET,
+            AppError::InvalidDepositBatchAccounts
+        );
+
+        // validate amounts
+        require!(
+            items.iter().all(|item| item.amount > 0),
+            AppError::InvalidAmount
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DepositBatch<'info>>,
+    items: Vec<DepositItem>,
+    as_heartbeat: bool,
+) -> Result<()> {
+    // validate inputs
+    ctx.accounts.validate(&items, ctx.remaining_accounts)?;
+
+    let vault_key = ctx.accounts.vault.key();
+    let testator_key = ctx.accounts.testator.key();
+
+    for (item, asset_accounts) in items.iter().zip(
+        ctx.remaining_accounts
+            .chunks(DEPOSIT_BATCH_ACCOUNTS_PER_ASSET),
+    ) {
+        let mint_info = &asset_accounts[0];
+        let testator_token_info = &asset_accounts[1];
+        let vault_token_info = &asset_accounts[2];
+
+        // validate accounts match item
+        let mint = Account::<Mint>::try_from(mint_info)?;
+        require_keys_eq!(mint.key(), item.mint, AppError::InvalidDepositBatchAccounts);
+
+        let testator_token_account = Account::<TokenAccount>::try_from(testator_token_info)?;
+        require!(
+            testator_token_account.mint == item.mint
+                && testator_token_account.owner == testator_key,
+            AppError::InvalidDepositBatchAccounts
+        );
+        require!(
+            testator_token_account.amount >= item.amount,
+            AppError::InsufficientBalance
+        );
+
+        require_keys_eq!(
+            vault_token_info.key(),
+            get_associated_token_address(&vault_key, &item.mint),
+            AppError::InvalidDepositBatchAccounts
+        );
+
+        // create vault token account if needed
+        let create_accounts = Create {
+            payer: ctx.accounts.testator.to_account_info(),
+            associated_token: vault_token_info.clone(),
+            authority: ctx.accounts.vault.to_account_info(),
+            mint: mint_info.clone(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        };
+
+        let create_ctx = CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            create_accounts,
+        );
+
+        create_idempotent(create_ctx)?;
+
+        // transfer from testator to vault
+        let token_transfer_accounts = TokenTransfer {
+            from: testator_token_info.clone(),
+            to: vault_token_info.clone(),
+            authority: ctx.accounts.testator.to_account_info(),
+        };
+
+        let token_transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_transfer_accounts,
+        );
+
+        token_transfer(token_transfer_ctx, item.amount)?;
+
+        // update inventory
+        WillInventory::record(
+            &mut ctx.accounts.will_inventory,
+            ctx.accounts.testator.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            item.mint,
+            AssetKind::of(&mint),
+            InventoryChange::Deposit(item.amount),
+        )?;
+    }
+
+    // activate will on first deposit, refresh heartbeat only if requested
+    let clock = Clock::get()?;
+    let will = &mut ctx.accounts.will;
+    will.apply_deposit(as_heartbeat, &ctx.accounts.config, clock.unix_timestamp)?;
+
+    Ok(())
+}
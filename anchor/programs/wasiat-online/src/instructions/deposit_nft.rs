@@ -24,6 +24,14 @@ pub struct DepositNft<'info> {
     )]
     pub will: Account<'info, Will>,
 
+    /// Config for heartbeat interval and validate deposits not paused
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        constraint = !config.is_paused(PAUSE_DEPOSIT) @ AppError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
     /// Nft mint (supply should be 1)
     #[account(
         constraint = nft_mint.supply == 1 @ AppError::InvalidNftSupply,
@@ -57,6 +65,14 @@ pub struct DepositNft<'info> {
     )]
     pub vault: SystemAccount<'info>,
 
+    /// Will inventory (grown for new mints)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -74,7 +90,7 @@ impl<'info> DepositNft<'info> {
     }
 }
 
-pub fn handler(ctx: Context<DepositNft>) -> Result<()> {
+pub fn handler(ctx: Context<DepositNft>, as_heartbeat: bool) -> Result<()> {
     // validate inputs
     ctx.accounts.validate()?;
 
@@ -92,15 +108,20 @@ pub fn handler(ctx: Context<DepositNft>) -> Result<()> {
 
     token_transfer(nft_transfer_ctx, NFT_AMOUNT as u64)?;
 
-    // update will status if first deposit
-    let will = &mut ctx.accounts.will;
-    if will.status == WillStatus::Created {
-        will.status = WillStatus::Active
-    }
-
-    // update last_heartbeat
+    // activate will on first deposit, refresh heartbeat only if requested
     let clock = Clock::get()?;
-    will.last_heartbeat = clock.unix_timestamp;
+    let will = &mut ctx.accounts.will;
+    will.apply_deposit(as_heartbeat, &ctx.accounts.config, clock.unix_timestamp)?;
+
+    // update inventory
+    WillInventory::record(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.testator.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.nft_mint.key(),
+        AssetKind::Nft,
+        InventoryChange::Deposit(NFT_AMOUNT as u64),
+    )?;
 
     Ok(())
 }
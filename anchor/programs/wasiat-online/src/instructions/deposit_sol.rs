@@ -21,6 +21,14 @@ pub struct DepositSol<'info> {
     )]
     pub will: Account<'info, Will>,
 
+    /// Config for heartbeat interval and validate deposits not paused
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        constraint = !config.is_paused(PAUSE_DEPOSIT) @ AppError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
     /// Vault pda for saving SOL
     #[account(
         mut,
@@ -29,6 +37,14 @@ pub struct DepositSol<'info> {
     )]
     pub vault: SystemAccount<'info>,
 
+    /// Will inventory (grown for new mints)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -55,7 +71,7 @@ impl<'info> DepositSol<'info> {
     }
 }
 
-pub fn handler(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
+pub fn handler(ctx: Context<DepositSol>, amount: u64, as_heartbeat: bool) -> Result<()> {
     // validate inputs
     ctx.accounts.validate(amount)?;
 
@@ -72,15 +88,18 @@ pub fn handler(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
 
     transfer(transfer_ctx, amount)?;
 
-    // update will status if first deposit
+    // activate will on first deposit, refresh heartbeat only if requested
+    let clock = Clock::get()?;
     let will = &mut ctx.accounts.will;
-    if will.status == WillStatus::Created {
-        will.status = WillStatus::Active;
-    }
+    will.apply_deposit(as_heartbeat, &ctx.accounts.config, clock.unix_timestamp)?;
 
-    // update last_heartbeat
-    let clock = Clock::get()?;
-    will.last_heartbeat = clock.unix_timestamp;
+    // update inventory
+    WillInventory::record_sol(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.testator.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        InventoryChange::Deposit(amount),
+    )?;
 
     Ok(())
 }
@@ -25,6 +25,14 @@ pub struct DepositSplToken<'info> {
     )]
     pub will: Account<'info, Will>,
 
+    /// Config for heartbeat interval and validate deposits not paused
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        constraint = !config.is_paused(PAUSE_DEPOSIT) @ AppError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
     /// Token mint
     pub mint: Account<'info, Mint>,
 
@@ -53,6 +61,14 @@ pub struct DepositSplToken<'info> {
     )]
     pub vault: SystemAccount<'info>,
 
+    /// Will inventory (grown for new mints)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -73,7 +89,7 @@ impl<'info> DepositSplToken<'info> {
     }
 }
 
-pub fn handler(ctx: Context<DepositSplToken>, amount: u64) -> Result<()> {
+pub fn handler(ctx: Context<DepositSplToken>, amount: u64, as_heartbeat: bool) -> Result<()> {
     // validate inputs
     ctx.accounts.validate(amount)?;
 
@@ -91,15 +107,20 @@ pub fn handler(ctx: Context<DepositSplToken>, amount: u64) -> Result<()> {
 
     token_transfer(token_transfer_ctx, amount)?;
 
-    // update will status if first deposit
-    let will = &mut ctx.accounts.will;
-    if will.status == WillStatus::Created {
-        will.status = WillStatus::Active;
-    }
-
-    // update last_hearbeat
+    // activate will on first deposit, refresh heartbeat only if requested
     let clock = Clock::get()?;
-    will.last_heartbeat = clock.unix_timestamp;
+    let will = &mut ctx.accounts.will;
+    will.apply_deposit(as_heartbeat, &ctx.accounts.config, clock.unix_timestamp)?;
+
+    // update inventory
+    WillInventory::record(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.testator.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.mint.key(),
+        AssetKind::of(&ctx.accounts.mint),
+        InventoryChange::Deposit(amount),
+    )?;
 
     Ok(())
 }
@@ -0,0 +1,127 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::{prelude::*, solana_program::stake::state::StakeAuthorize};
+use anchor_spl::stake::{authorize, Authorize, Stake, StakeAccount};
+
+#[derive(Accounts)]
+pub struct DepositStake<'info> {
+    /// Testator depositing stake account
+    #[account(mut)]
+    pub testator: Signer<'info>,
+
+    /// Will account - must be owned by testator
+    #[account(
+        mut,
+        seeds = [
+            WILL_SEED.as_bytes(),
+            testator.key().as_ref(),
+            will.beneficiary.as_ref()
+        ],
+        bump = will.bump,
+        constraint = will.testator == testator.key() @ AppError::Unauthorized,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Config for heartbeat interval and validate deposits not paused
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        constraint = !config.is_paused(PAUSE_DEPOSIT) @ AppError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Native stake account (staker and withdrawer must be testator)
+    #[account(mut)]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// Vault pda (new staker and withdrawer authority)
+    #[account(
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Will inventory (grown for new stake accounts)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub stake_program: Program<'info, Stake>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositStake<'info> {
+    pub fn validate(&self) -> Result<()> {
+        // validate will status
+        require!(
+            matches!(self.will.status, WillStatus::Created | WillStatus::Active),
+            AppError::InvalidWillStatus,
+        );
+
+        // validate testator holds both authorities
+        let authorized = self
+            .stake_account
+            .authorized()
+            .ok_or(AppError::InvalidStakeAccount)?;
+        let testator = self.testator.key();
+        require!(
+            authorized.staker == testator && authorized.withdrawer == testator,
+            AppError::StakeAuthorityMismatch
+        );
+
+        // validate no lockup (withdrawer change would need custodian)
+        let lockup = self
+            .stake_account
+            .lockup()
+            .ok_or(AppError::InvalidStakeAccount)?;
+        require!(
+            !lockup.is_in_force(&self.clock, None),
+            AppError::StakeLockupInForce
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<DepositStake>, as_heartbeat: bool) -> Result<()> {
+    // validate inputs
+    ctx.accounts.validate()?;
+
+    // reassign staker and withdrawer authorities from testator to vault
+    for stake_authorize in [StakeAuthorize::Staker, StakeAuthorize::Withdrawer] {
+        let authorize_accounts = Authorize {
+            stake: ctx.accounts.stake_account.to_account_info(),
+            authorized: ctx.accounts.testator.to_account_info(),
+            new_authorized: ctx.accounts.vault.to_account_info(),
+            clock: ctx.accounts.clock.to_account_info(),
+        };
+
+        let authorize_ctx = CpiContext::new(
+            ctx.accounts.stake_program.to_account_info(),
+            authorize_accounts,
+        );
+
+        authorize(authorize_ctx, stake_authorize, None)?;
+    }
+
+    // record stake balance in inventory
+    let stake_balance = ctx.accounts.stake_account.to_account_info().lamports();
+    WillInventory::record(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.testator.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.stake_account.key(),
+        AssetKind::Stake,
+        InventoryChange::Deposit(stake_balance),
+    )?;
+
+    // activate will on first deposit, refresh heartbeat only if requested
+    let clock = Clock::get()?;
+    let will = &mut ctx.accounts.will;
+    will.apply_deposit(as_heartbeat, &ctx.accounts.config, clock.unix_timestamp)?;
+
+    Ok(())
+}
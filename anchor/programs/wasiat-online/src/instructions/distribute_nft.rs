@@ -0,0 +1,200 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer as token_transfer, Mint, Token, TokenAccount, Transfer as TokenTransfer},
+};
+
+#[derive(Accounts)]
+pub struct DistributeNft<'info> {
+    /// Executor distributing NFT (pays nft fee and missing token account)
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// Will account - must be triggered and name executor
+    #[account(
+        mut,
+        constraint = will.status == WillStatus::Triggered || will.status == WillStatus::Claimed @ AppError::InvalidWillStatus,
+        constraint = will.executor == Some(executor.key()) @ AppError::Unauthorized,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Beneficiary receiving NFT (not a signer)
+    #[account(
+        constraint = beneficiary.key() == will.beneficiary @ AppError::Unauthorized,
+    )]
+    pub beneficiary: SystemAccount<'info>,
+
+    /// Nft mint (supply should be 1)
+    #[account(
+        constraint = nft_mint.supply == 1 @ AppError::InvalidNftSupply,
+        constraint = nft_mint.decimals == 0 @ AppError::InvalidNftDecimals,
+    )]
+    pub nft_mint: Account<'info, Mint>,
+
+    /// Vault's nft account (source)
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = vault,
+        constraint = vault_nft_account.amount == 1 @ AppError::NoAssetsToClaim,
+    )]
+    pub vault_nft_account: Account<'info, TokenAccount>,
+
+    /// Beneficiary's nft account (destination - created by executor if needed)
+    #[account(
+        init_if_needed,
+        payer = executor,
+        associated_token::mint = nft_mint,
+        associated_token::authority = beneficiary
+    )]
+    pub beneficiary_nft_account: Account<'info, TokenAccount>,
+
+    /// Vault pda (authority for vault_nft_account)
+    #[account(
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Config for fee calculation
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        constraint = !config.is_paused(PAUSE_CLAIM) @ AppError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Fee vault for collection SOL fees (nft fee in sol)
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED.as_bytes()],
+        bump,
+    )]
+    pub fee_vault: SystemAccount<'info>,
+
+    /// CHECK: will referrer receiving its share of nft fee (required if will has one)
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Will inventory (grown for new mints)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DistributeNft<'info> {
+    pub fn validate(&self) -> Result<()> {
+        // validate executor SOL for fee
+        let nft_fee = self.will.fees(&self.config).nft_fee_lamports;
+        if nft_fee > 0 {
+            let executor_balance = self.executor.lamports();
+            let min_rent = Rent::get()?.minimum_balance(0);
+            require!(
+                executor_balance >= nft_fee + min_rent,
+                AppError::InsuffcientBalanceForFees
+            );
+        }
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<DistributeNft>) -> Result<()> {
+    // validations
+    ctx.accounts.validate()?;
+
+    // validate beneficiary can receive now (release date, contingent takeover)
+    let clock = Clock::get()?;
+    ctx.accounts
+        .will
+        .claimant_is_contingent(ctx.accounts.beneficiary.key(), clock.unix_timestamp)?;
+
+    let will = &mut ctx.accounts.will;
+    let config = &ctx.accounts.config;
+
+    // prepare pda signer seed for vault
+    let will_key = will.key();
+    let vault_seeds = &[VAULT_SEED.as_bytes(), will_key.as_ref(), &[will.vault_bump]];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    // split service fee with referrer (no bps compensation on nfts)
+    let nft_service_fee = will.fees(config).nft_fee_lamports;
+    let referrer_fee = will.referrer_fee(nft_service_fee);
+    let protocol_fee = nft_service_fee - referrer_fee;
+
+    // transfer service fee from executor to fee vault
+    if protocol_fee > 0 {
+        let fee_transfer_account = Transfer {
+            from: ctx.accounts.executor.to_account_info(),
+            to: ctx.accounts.fee_vault.to_account_info(),
+        };
+
+        let fee_transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            fee_transfer_account,
+        );
+
+        transfer(fee_transfer_ctx, protocol_fee)?;
+    }
+
+    // transfer referrer share from executor to referrer
+    if referrer_fee > 0 {
+        let Some(referrer) = ctx.accounts.referrer.as_ref() else {
+            return err!(AppError::ReferrerMismatch);
+        };
+        will.validate_referrer(referrer.key())?;
+
+        let referrer_transfer_account = Transfer {
+            from: ctx.accounts.executor.to_account_info(),
+            to: referrer.to_account_info(),
+        };
+
+        let referrer_transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            referrer_transfer_account,
+        );
+
+        transfer(referrer_transfer_ctx, referrer_fee)?;
+    }
+
+    // transfer nft from vault to beneficiary
+    let nft_transfer_account = TokenTransfer {
+        from: ctx.accounts.vault_nft_account.to_account_info(),
+        to: ctx.accounts.beneficiary_nft_account.to_account_info(),
+        authority: ctx.accounts.vault.to_account_info(),
+    };
+
+    let nft_transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        nft_transfer_account,
+        vault_signer_seeds,
+    );
+
+    token_transfer(nft_transfer_ctx, NFT_AMOUNT as u64)?;
+
+    // update will status
+    will.status = WillStatus::Claimed;
+
+    // update inventory
+    WillInventory::record(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.executor.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.nft_mint.key(),
+        AssetKind::Nft,
+        InventoryChange::Claim(NFT_AMOUNT as u64),
+    )?;
+
+    Ok(())
+}
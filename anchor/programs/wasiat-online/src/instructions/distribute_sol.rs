@@ -0,0 +1,175 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+#[derive(Accounts)]
+pub struct DistributeSol<'info> {
+    /// Executor distributing SOL on behalf of beneficiary
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// Will account - must be triggered and name executor
+    #[account(
+        mut,
+        constraint = will.status == WillStatus::Triggered || will.status == WillStatus::Claimed @ AppError::InvalidWillStatus,
+        constraint = will.staked_lamports == 0 @ AppError::VaultStakeOutstanding,
+        constraint = will.executor == Some(executor.key()) @ AppError::Unauthorized,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Beneficiary receiving SOL (not a signer)
+    #[account(
+        mut,
+        constraint = beneficiary.key() == will.beneficiary @ AppError::Unauthorized,
+    )]
+    pub beneficiary: SystemAccount<'info>,
+
+    /// Sol vault pda
+    #[account(
+        mut,
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Config for fee configuration
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        constraint = !config.is_paused(PAUSE_CLAIM) @ AppError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Fee schedule (tiers and fee bounds applied to will fee)
+    #[account(
+        seeds = [FEE_SCHEDULE_SEED.as_bytes()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    /// CHECK: will referrer receiving its share of service fee (required if will has one)
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Fee vault for service fees
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED.as_bytes()],
+        bump,
+    )]
+    pub fee_vault: SystemAccount<'info>,
+
+    /// Will inventory (grown for new mints)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DistributeSol>) -> Result<()> {
+    // validate beneficiary can receive now (release date, contingent takeover)
+    let clock = Clock::get()?;
+    ctx.accounts
+        .will
+        .claimant_is_contingent(ctx.accounts.beneficiary.key(), clock.unix_timestamp)?;
+
+    let will = &mut ctx.accounts.will;
+    let vault_balance = ctx.accounts.vault.lamports();
+
+    // keep minimum rent in vault to prevent account closure
+    let min_rent = Rent::get()?.minimum_balance(0);
+    let remaining = vault_balance.saturating_sub(min_rent);
+
+    // release only the vested portion
+    let releasable = will.releasable_amount(
+        will.sol_vesting_total,
+        remaining,
+        will.claimed_so_far,
+        clock.unix_timestamp,
+    )?;
+    require!(releasable > 0, AppError::NothingVested);
+
+    // calculate service fee and executor compensation
+    let service_fee =
+        will.sol_service_fee(&ctx.accounts.config, &ctx.accounts.fee_schedule, releasable);
+    let executor_fee = (releasable as u128 * will.executor_fee_bps as u128 / 10_000) as u64;
+    let final_claimable = releasable
+        .saturating_sub(service_fee)
+        .saturating_sub(executor_fee);
+
+    require!(final_claimable > 0, AppError::NoClaimableAmount);
+
+    // pda signer seeds for vault
+    let will_key = will.key();
+    let vault_seeds = &[VAULT_SEED.as_bytes(), will_key.as_ref(), &[will.vault_bump]];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    // split service fee with referrer
+    let referrer_fee = will.referrer_fee(service_fee);
+    let protocol_fee = service_fee - referrer_fee;
+
+    let mut fee_transfers = vec![
+        (protocol_fee, ctx.accounts.fee_vault.to_account_info()),
+        (executor_fee, ctx.accounts.executor.to_account_info()),
+    ];
+    if referrer_fee > 0 {
+        let Some(referrer) = ctx.accounts.referrer.as_ref() else {
+            return err!(AppError::ReferrerMismatch);
+        };
+        will.validate_referrer(referrer.key())?;
+        fee_transfers.push((referrer_fee, referrer.to_account_info()));
+    }
+
+    // transfer service fee to fee vault and referrer, executor compensation to executor
+    for (fee, to) in fee_transfers {
+        if fee > 0 {
+            let fee_transfer_accounts = Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to,
+            };
+
+            let fee_transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                fee_transfer_accounts,
+                vault_signer_seeds,
+            );
+
+            transfer(fee_transfer_ctx, fee)?;
+        }
+    }
+
+    // transfer remaining SOL to beneficiary
+    let claim_transfer_accounts = Transfer {
+        from: ctx.accounts.vault.to_account_info(),
+        to: ctx.accounts.beneficiary.to_account_info(),
+    };
+
+    let claim_transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.system_program.to_account_info(),
+        claim_transfer_accounts,
+        vault_signer_seeds,
+    );
+
+    transfer(claim_transfer_ctx, final_claimable)?;
+
+    // update will status
+    will.claimed_so_far = will
+        .claimed_so_far
+        .checked_add(releasable)
+        .ok_or(AppError::Overflow)?;
+    will.status = WillStatus::Claimed;
+
+    // update inventory
+    WillInventory::record_sol(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.executor.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        InventoryChange::Claim(releasable),
+    )?;
+
+    Ok(())
+}
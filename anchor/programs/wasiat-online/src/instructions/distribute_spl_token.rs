@@ -0,0 +1,242 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer as token_transfer, Mint, Token, TokenAccount, Transfer as TokenTransfer},
+};
+
+#[derive(Accounts)]
+pub struct DistributeSplToken<'info> {
+    /// Executor distributing spl token (pays for missing token accounts)
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// Will account - must be triggered and name executor
+    #[account(
+        mut,
+        constraint = will.status == WillStatus::Triggered || will.status == WillStatus::Claimed @ AppError::InvalidWillStatus,
+        constraint = will.executor == Some(executor.key()) @ AppError::Unauthorized,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Beneficiary receiving tokens (not a signer)
+    #[account(
+        constraint = beneficiary.key() == will.beneficiary @ AppError::Unauthorized,
+    )]
+    pub beneficiary: SystemAccount<'info>,
+
+    /// Token mint
+    pub mint: Account<'info, Mint>,
+
+    /// Vault's token account (source)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        constraint = vault_token_account.amount > 0 @ AppError::NoAssetsToClaim,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Beneficiary's token account (destination - created by executor if needed)
+    #[account(
+        init_if_needed,
+        payer = executor,
+        associated_token::mint = mint,
+        associated_token::authority = beneficiary,
+    )]
+    pub beneficiary_token_account: Account<'info, TokenAccount>,
+
+    /// Executor's token account (compensation)
+    #[account(
+        init_if_needed,
+        payer = executor,
+        associated_token::mint = mint,
+        associated_token::authority = executor,
+    )]
+    pub executor_token_account: Account<'info, TokenAccount>,
+
+    /// Claim record tracking vested tokens released for this mint
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + ClaimRecord::INIT_SPACE,
+        seeds = [
+            CLAIM_RECORD_SEED.as_bytes(),
+            will.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub claim_record: Account<'info, ClaimRecord>,
+
+    /// Fee vault's token account (for service fees)
+    #[account(
+        init_if_needed,
+        payer = executor,
+        associated_token::mint = mint,
+        associated_token::authority = fee_vault
+    )]
+    pub fee_vault_token_account: Account<'info, TokenAccount>,
+
+    /// Will referrer's token account receiving its share of service fee (required if will has one)
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub referrer_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Vault pda (authority for vault_token_account)
+    #[account(
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Fee vault pda (authority for fee_vault_token_account)
+    #[account(
+        seeds = [FEE_VAULT_SEED.as_bytes()],
+        bump,
+    )]
+    pub fee_vault: SystemAccount<'info>,
+
+    /// Config - fee configuration and validate claims not paused
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        constraint = !config.is_paused(PAUSE_CLAIM) @ AppError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Fee schedule (tiers and fee bounds applied to will fee)
+    #[account(
+        seeds = [FEE_SCHEDULE_SEED.as_bytes()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    /// Will inventory (grown for new mints)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DistributeSplToken>) -> Result<()> {
+    // validate beneficiary can receive now (release date, contingent takeover)
+    let clock = Clock::get()?;
+    ctx.accounts
+        .will
+        .claimant_is_contingent(ctx.accounts.beneficiary.key(), clock.unix_timestamp)?;
+
+    let will = &mut ctx.accounts.will;
+    let claim_record = &mut ctx.accounts.claim_record;
+    let remaining = ctx.accounts.vault_token_account.amount;
+
+    // snapshot vesting total on first release of this mint
+    if claim_record.vesting_total == 0 {
+        claim_record.vesting_total = remaining;
+    }
+
+    // release only the vested portion
+    let releasable = will.releasable_amount(
+        claim_record.vesting_total,
+        remaining,
+        claim_record.claimed_so_far,
+        clock.unix_timestamp,
+    )?;
+    require!(releasable > 0, AppError::NothingVested);
+
+    // calculate service fee and executor compensation
+    let service_fee = will.token_service_fee(
+        &ctx.accounts.config,
+        &ctx.accounts.fee_schedule,
+        &ctx.accounts.mint.key(),
+        releasable,
+    );
+    let executor_fee = (releasable as u128 * will.executor_fee_bps as u128 / 10_000) as u64;
+    let claimable_amount = releasable
+        .saturating_sub(service_fee)
+        .saturating_sub(executor_fee);
+
+    require!(claimable_amount > 0, AppError::NoClaimableAmount);
+
+    // prepare pda signer seeds
+    let will_key = will.key();
+    let vault_seeds = &[VAULT_SEED.as_bytes(), will_key.as_ref(), &[will.vault_bump]];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    // split service fee with referrer
+    let referrer_fee = will.referrer_fee(service_fee);
+    let protocol_fee = service_fee - referrer_fee;
+
+    let mut transfers = vec![
+        (
+            protocol_fee,
+            ctx.accounts.fee_vault_token_account.to_account_info(),
+        ),
+        (
+            executor_fee,
+            ctx.accounts.executor_token_account.to_account_info(),
+        ),
+        (
+            claimable_amount,
+            ctx.accounts.beneficiary_token_account.to_account_info(),
+        ),
+    ];
+    if referrer_fee > 0 {
+        let Some(referrer_token_account) = ctx.accounts.referrer_token_account.as_ref() else {
+            return err!(AppError::ReferrerMismatch);
+        };
+        will.validate_referrer(referrer_token_account.owner)?;
+        transfers.push((referrer_fee, referrer_token_account.to_account_info()));
+    }
+
+    // transfer service fee, referrer share, executor compensation and remaining tokens to beneficiary
+    for (amount, to) in transfers {
+        if amount > 0 {
+            let transfer_accounts = TokenTransfer {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                to,
+                authority: ctx.accounts.vault.to_account_info(),
+            };
+
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_accounts,
+                vault_signer_seeds,
+            );
+
+            token_transfer(transfer_ctx, amount)?;
+        }
+    }
+
+    // update claim record
+    claim_record.will = will_key;
+    claim_record.mint = ctx.accounts.mint.key();
+    claim_record.claimed_so_far = claim_record
+        .claimed_so_far
+        .checked_add(releasable)
+        .ok_or(AppError::Overflow)?;
+    claim_record.bump = ctx.bumps.claim_record;
+
+    // update will status
+    will.status = WillStatus::Claimed;
+
+    // update inventory
+    WillInventory::record(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.executor.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.mint.key(),
+        AssetKind::of(&ctx.accounts.mint),
+        InventoryChange::Claim(releasable),
+    )?;
+
+    Ok(())
+}
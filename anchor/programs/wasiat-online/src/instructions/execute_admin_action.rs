@@ -0,0 +1,123 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program::invoke_signed,
+    },
+};
+
+#[derive(Accounts)]
+pub struct ExecuteAdminAction<'info> {
+    /// Anyone executing an approved proposal
+    pub executor: Signer<'info>,
+
+    /// Admin set (read only, an executed update_admin_set writes it)
+    #[account(
+        seeds = [ADMIN_SET_SEED.as_bytes()],
+        bump = admin_set.bump,
+    )]
+    pub admin_set: Account<'info, AdminSet>,
+
+    /// Admin proposal account (closed, rent back to proposer)
+    #[account(
+        mut,
+        seeds = [
+            ADMIN_PROPOSAL_SEED.as_bytes(),
+            &admin_proposal.index.to_le_bytes(),
+        ],
+        bump = admin_proposal.bump,
+        has_one = admin_set,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub admin_proposal: Account<'info, AdminProposal>,
+
+    /// Member that proposed
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+
+    /// CHECK: this program, target of every admin action
+    #[account(address = crate::ID)]
+    pub wasiat_program: UncheckedAccount<'info>,
+}
+
+impl<'info> ExecuteAdminAction<'info> {
+    pub fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        // validate enough current members approved
+        require!(
+            self.admin_proposal.approval_count(&self.admin_set)
+                >= self.admin_set.threshold as usize,
+            AppError::ApprovalThresholdNotMet
+        );
+
+        // validate remaining accounts match stored metas in order
+        let metas = &self.admin_proposal.accounts;
+        require!(
+            remaining_accounts.len() == metas.len()
+                && remaining_accounts
+                    .iter()
+                    .zip(metas.iter())
+                    .all(|(account, meta)| account.key() == meta.pubkey),
+            AppError::ActionAccountMismatch
+        );
+
+        // validate co-signers (signers besides the admin signer) signed this transaction
+        let admin_signer = Pubkey::create_program_address(
+            &[ADMIN_SIGNER_SEED.as_bytes(), &[self.admin_set.signer_bump]],
+            &crate::ID,
+        )
+        .map_err(|_| AppError::InvalidAdminSet)?;
+        require!(
+            remaining_accounts
+                .iter()
+                .zip(metas.iter())
+                .all(|(account, meta)| !meta.is_signer
+                    || meta.pubkey == admin_signer
+                    || account.is_signer),
+            AppError::MissingActionCoSigner
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteAdminAction<'info>>) -> Result<()> {
+    // validations
+    ctx.accounts.validate(ctx.remaining_accounts)?;
+
+    let admin_proposal = &ctx.accounts.admin_proposal;
+
+    // build stored instruction
+    let action_ix = Instruction {
+        program_id: crate::ID,
+        accounts: admin_proposal
+            .accounts
+            .iter()
+            .map(|meta| AccountMeta {
+                pubkey: meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: admin_proposal.data.clone(),
+    };
+
+    let mut action_account_infos = ctx.remaining_accounts.to_vec();
+    action_account_infos.push(ctx.accounts.wasiat_program.to_account_info());
+
+    // prepare pda signer seeds for admin signer
+    let admin_signer_seeds = &[
+        ADMIN_SIGNER_SEED.as_bytes(),
+        &[ctx.accounts.admin_set.signer_bump],
+    ];
+
+    // execute admin action signed by admin signer (config authority)
+    invoke_signed(
+        &action_ix,
+        &action_account_infos,
+        &[&admin_signer_seeds[..]],
+    )?;
+
+    Ok(())
+}
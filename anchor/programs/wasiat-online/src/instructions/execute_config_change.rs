@@ -0,0 +1,46 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ExecuteConfigChange<'info> {
+    /// Anyone applying a change once its timelock elapsed
+    pub executor: Signer<'info>,
+
+    /// The program config account to be updated
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Pending config change (closed, rent back to proposer)
+    #[account(
+        mut,
+        seeds = [PENDING_CONFIG_CHANGE_SEED.as_bytes()],
+        bump = pending_config_change.bump,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub pending_config_change: Account<'info, PendingConfigChange>,
+
+    /// Authority that queued the change
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+    // validate timelock elapsed
+    let current_time = Clock::get()?.unix_timestamp;
+    let pending_config_change = &ctx.accounts.pending_config_change;
+    require!(
+        current_time >= pending_config_change.effective_at,
+        AppError::ConfigChangeNotReady
+    );
+
+    // apply change, bounds may have moved since queueing
+    let change = pending_config_change.change;
+    change.apply(&mut ctx.accounts.config)?;
+
+    Ok(())
+}
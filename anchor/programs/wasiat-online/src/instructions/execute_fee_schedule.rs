@@ -0,0 +1,53 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ExecuteFeeSchedule<'info> {
+    /// Anyone applying a schedule once its timelock elapsed (pays fee schedule rent if missing)
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// Fee schedule (create if needed)
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + FeeSchedule::INIT_SPACE,
+        seeds = [FEE_SCHEDULE_SEED.as_bytes()],
+        bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    /// Pending fee schedule (closed, rent back to proposer)
+    #[account(
+        mut,
+        seeds = [PENDING_FEE_SCHEDULE_SEED.as_bytes()],
+        bump = pending_fee_schedule.bump,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub pending_fee_schedule: Account<'info, PendingFeeSchedule>,
+
+    /// Fee manager that queued the schedule
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ExecuteFeeSchedule>) -> Result<()> {
+    // validate timelock elapsed
+    let current_time = Clock::get()?.unix_timestamp;
+    let pending_fee_schedule = &ctx.accounts.pending_fee_schedule;
+    require!(
+        current_time >= pending_fee_schedule.effective_at,
+        AppError::ConfigChangeNotReady
+    );
+
+    // apply schedule
+    let fee_schedule = &mut ctx.accounts.fee_schedule;
+    pending_fee_schedule.apply(fee_schedule)?;
+    fee_schedule.effective_at = current_time;
+    fee_schedule.bump = ctx.bumps.fee_schedule;
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program::invoke_signed,
+    },
+};
+
+#[derive(Accounts)]
+pub struct ExecuteWillAction<'info> {
+    /// Keeper executing action
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// Will account - must be triggered
+    #[account(
+        mut,
+        constraint = will.status == WillStatus::Triggered || will.status == WillStatus::Claimed @ AppError::InvalidWillStatus,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Will action account (closed, rent back to vault)
+    #[account(
+        mut,
+        seeds = [
+            WILL_ACTION_SEED.as_bytes(),
+            will.key().as_ref(),
+            &[will_action.index],
+        ],
+        bump = will_action.bump,
+        has_one = will,
+        close = vault,
+    )]
+    pub will_action: Account<'info, WillAction>,
+
+    /// Vault pda (signer for will action)
+    #[account(
+        mut,
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// CHECK: target program of will action
+    #[account(address = will_action.program_id)]
+    pub target_program: UncheckedAccount<'info>,
+
+    /// Program allowlist for target validation
+    #[account(
+        seeds = [PROGRAM_ALLOWLIST_SEED.as_bytes()],
+        bump = program_allowlist.bump,
+    )]
+    pub program_allowlist: Account<'info, ProgramAllowlist>,
+
+    /// Config account for validation
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+        constraint = !config.is_paused(PAUSE_CLAIM) @ AppError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> ExecuteWillAction<'info> {
+    pub fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        // validate target program is still allowlisted
+        require!(
+            self.program_allowlist
+                .is_allowed(&self.will_action.program_id),
+            AppError::ProgramNotAllowed
+        );
+
+        // validate remaining accounts match stored metas in order
+        let metas = &self.will_action.accounts;
+        require!(
+            remaining_accounts.len() == metas.len()
+                && remaining_accounts
+                    .iter()
+                    .zip(metas.iter())
+                    .all(|(account, meta)| account.key() == meta.pubkey),
+            AppError::ActionAccountMismatch
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteWillAction<'info>>) -> Result<()> {
+    // validations
+    ctx.accounts.validate(ctx.remaining_accounts)?;
+
+    let will_action = &ctx.accounts.will_action;
+
+    // build stored instruction
+    let action_ix = Instruction {
+        program_id: will_action.program_id,
+        accounts: will_action
+            .accounts
+            .iter()
+            .map(|meta| AccountMeta {
+                pubkey: meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: will_action.data.clone(),
+    };
+
+    let mut action_account_infos = ctx.remaining_accounts.to_vec();
+    action_account_infos.push(ctx.accounts.target_program.to_account_info());
+
+    // prepare pda signer seeds for vault
+    let will_key = ctx.accounts.will.key();
+    let vault_seeds = &[
+        VAULT_SEED.as_bytes(),
+        will_key.as_ref(),
+        &[ctx.accounts.will.vault_bump],
+    ];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    // execute will action signed by vault
+    invoke_signed(&action_ix, &action_account_infos, vault_signer_seeds)?;
+
+    // update will action counter
+    let will = &mut ctx.accounts.will;
+    will.action_count = will
+        .action_count
+        .checked_sub(1)
+        .ok_or(AppError::Underflow)?;
+
+    Ok(())
+}
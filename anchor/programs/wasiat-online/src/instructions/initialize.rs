@@ -1,4 +1,4 @@
-use crate::{constants::*, error::AppError, state::Config};
+use crate::{constants::*, error::AppError, state::*};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -18,6 +18,26 @@ pub struct Initialize<'info> {
     )]
     pub config: Account<'info, Config>,
 
+    /// Admin roles, unassigned until set_admin_role
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AdminRoles::INIT_SPACE,
+        seeds = [ADMIN_ROLES_SEED.as_bytes()],
+        bump
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+
+    /// Fee schedule, flat will fee until queue_fee_schedule
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + FeeSchedule::INIT_SPACE,
+        seeds = [FEE_SCHEDULE_SEED.as_bytes()],
+        bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
     /// Vault pda for fee collection
     #[account(
         mut,
@@ -33,6 +53,7 @@ pub struct Initialize<'info> {
 impl<'info> Initialize<'info> {
     pub fn validate(
         &self,
+        fees: &FeeSnapshot,
         min_heartbeat_period: u32,
         max_heartbeat_period: u32,
         min_heartbeat_interval: u32,
@@ -48,6 +69,9 @@ impl<'info> Initialize<'info> {
         );
         require!(min_heartbeat_interval > 0, AppError::InvalidHeartbeatPeriod);
 
+        // validate fee caps
+        fees.validate()?;
+
         Ok(())
     }
 }
@@ -62,6 +86,10 @@ pub fn handler(
 ) -> Result<()> {
     // validate inputs
     ctx.accounts.validate(
+        &FeeSnapshot {
+            token_fee_bps,
+            nft_fee_lamports,
+        },
         min_heartbeat_period,
         max_heartbeat_period,
         min_heartbeat_interval,
@@ -77,9 +105,31 @@ pub fn handler(
     config.min_heartbeat_period = min_heartbeat_period;
     config.max_heartbeat_period = max_heartbeat_period;
     config.min_heartbeat_interval = min_heartbeat_interval;
-    config.paused = false;
+    config.pause_flags = 0;
     config.bump = ctx.bumps.config;
-    config.reserved = [0; 28];
+    config.paused_since = 0;
+    config.total_paused_seconds = 0;
+    config.last_pause_ended_at = 0;
+    config.pause_flags_migrated = true;
+    config.reserved = [0; 3];
+
+    // roles default to the config owner
+    let admin_roles = &mut ctx.accounts.admin_roles;
+    admin_roles.pauser = None;
+    admin_roles.fee_manager = None;
+    admin_roles.upgrader = None;
+    admin_roles.bump = ctx.bumps.admin_roles;
+
+    // flat fee schedule, no subscription or referrer share offered
+    let fee_schedule = &mut ctx.accounts.fee_schedule;
+    fee_schedule.sol_tiers = Vec::new();
+    fee_schedule.sol_min_fee = 0;
+    fee_schedule.sol_max_fee = 0;
+    fee_schedule.mint_fees = Vec::new();
+    fee_schedule.annual_fee_lamports = 0;
+    fee_schedule.referrer_share_bps = 0;
+    fee_schedule.effective_at = 0;
+    fee_schedule.bump = ctx.bumps.fee_schedule;
 
     Ok(())
 }
@@ -0,0 +1,55 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// Config owner paying rent for the new accounts
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Config account for authority validation (legacy pause mapped to pause flags)
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ AppError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Fee schedule (create if needed, for configs initialized before schedules)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + FeeSchedule::INIT_SPACE,
+        seeds = [FEE_SCHEDULE_SEED.as_bytes()],
+        bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    /// Admin roles (create if needed, for configs initialized before roles)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AdminRoles::INIT_SPACE,
+        seeds = [ADMIN_ROLES_SEED.as_bytes()],
+        bump,
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates accounts added after initialize and maps the legacy pause, existing accounts are left untouched
+pub fn handler(ctx: Context<MigrateConfig>) -> Result<()> {
+    // legacy paused configs stay fully paused
+    let clock = Clock::get()?;
+    ctx.accounts
+        .config
+        .migrate_pause_flags(clock.unix_timestamp);
+
+    // new accounts are zeroed: flat fee schedule (will fees apply) and unassigned roles
+    ctx.accounts.fee_schedule.bump = ctx.bumps.fee_schedule;
+    ctx.accounts.admin_roles.bump = ctx.bumps.admin_roles;
+
+    Ok(())
+}
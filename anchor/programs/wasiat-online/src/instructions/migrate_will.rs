@@ -0,0 +1,106 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+#[derive(Accounts)]
+pub struct MigrateWill<'info> {
+    /// Anyone paying rent for the grown account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: will account created with the smaller layout, validated in handler
+    #[account(mut, owner = crate::ID)]
+    pub will: UncheckedAccount<'info>,
+
+    /// Will inventory listing deposited mints
+    #[account(
+        init,
+        payer = payer,
+        space = WillInventory::space(0),
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
+    /// Config for trigger pause accounting and fee snapshot
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateWill<'info> {
+    pub fn validate(&self) -> Result<()> {
+        let will_info = self.will.to_account_info();
+
+        // validate will discriminator
+        require!(
+            will_info.data.borrow().starts_with(Will::DISCRIMINATOR),
+            AppError::InvalidWillAccount
+        );
+
+        // validate account still needs growing
+        require!(
+            will_info.data_len() < 8 + Will::INIT_SPACE,
+            AppError::WillAlreadyMigrated
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<MigrateWill>) -> Result<()> {
+    // validations
+    ctx.accounts.validate()?;
+
+    let will_info = ctx.accounts.will.to_account_info();
+    let new_size = 8 + Will::INIT_SPACE;
+
+    // top up rent for the grown account
+    let required_rent = Rent::get()?.minimum_balance(new_size);
+    let rent_top_up = required_rent.saturating_sub(will_info.lamports());
+    if rent_top_up > 0 {
+        let rent_transfer_accounts = Transfer {
+            from: ctx.accounts.payer.to_account_info(),
+            to: will_info.clone(),
+        };
+
+        let rent_transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            rent_transfer_accounts,
+        );
+
+        transfer(rent_transfer_ctx, rent_top_up)?;
+    }
+
+    // grow account, new fields zero-initialized (none / defaults)
+    will_info.resize(new_size)?;
+
+    // credit only the ongoing trigger pause since last heartbeat (earlier pauses can't extend expiry)
+    let mut will = Will::try_deserialize(&mut &will_info.data.borrow()[..])?;
+    let current_time = Clock::get()?.unix_timestamp;
+    let config = &ctx.accounts.config;
+    let paused_since_heartbeat = if config.paused_since > 0 {
+        current_time.saturating_sub(config.paused_since.max(will.last_heartbeat)) as u64
+    } else {
+        0
+    };
+    will.paused_seconds_at_heartbeat = config
+        .paused_seconds(current_time)
+        .saturating_sub(paused_since_heartbeat);
+
+    // lock current fees, later fee increases never apply to the migrated will
+    will.fee_snapshot = Some(config.current_fees());
+    will.try_serialize(&mut &mut will_info.data.borrow_mut()[..])?;
+
+    // set empty inventory (assets deposited before migration are recorded as they move)
+    let will_inventory = &mut ctx.accounts.will_inventory;
+    will_inventory.will = will_info.key();
+    will_inventory.bump = ctx.bumps.will_inventory;
+    will_inventory.entries = Vec::new();
+
+    Ok(())
+}
@@ -1,25 +1,122 @@
+// every instruction module exports its own `handler`, called by module path from lib.rs
+#![allow(ambiguous_glob_reexports)]
+
+pub mod add_will_action;
+pub mod adopt_current_fees;
+pub mod approve_admin_action;
+pub mod cancel_config_change;
+pub mod cancel_fee_schedule;
+pub mod claim_all;
+pub mod claim_authority;
 pub mod claim_nft;
 pub mod claim_sol;
 pub mod claim_spl_token;
+pub mod claim_stake;
+pub mod contribute_sol;
+pub mod contribute_spl_token;
+pub mod create_admin_set;
 pub mod create_will;
+pub mod deactivate_vault_stake;
+pub mod deposit_authority;
+pub mod deposit_batch;
 pub mod deposit_nft;
 pub mod deposit_sol;
 pub mod deposit_spl_token;
+pub mod deposit_stake;
+pub mod distribute_nft;
+pub mod distribute_sol;
+pub mod distribute_spl_token;
+pub mod execute_admin_action;
+pub mod execute_config_change;
+pub mod execute_fee_schedule;
+pub mod execute_will_action;
 pub mod initialize;
+pub mod migrate_config;
+pub mod migrate_will;
+pub mod propose_admin_action;
+pub mod queue_config_change;
+pub mod queue_fee_schedule;
+pub mod reclaim_unclaimed_sol;
+pub mod reclaim_unclaimed_spl_token;
+pub mod refund_contribution;
+pub mod refund_spl_contribution;
+pub mod remove_will_action;
 pub mod send_heartbeat;
+pub mod set_admin_role;
+pub mod set_config_authority;
+pub mod set_contingent_beneficiary;
+pub mod set_executor;
+pub mod set_fallback;
+pub mod set_payout_schedule;
+pub mod set_release_date;
+pub mod stake_vault_sol;
+pub mod subscribe;
 pub mod trigger_will;
+pub mod update_admin_set;
+pub mod update_allowlist;
 pub mod update_config;
+pub mod upgrade_program;
+pub mod withdraw_authority;
 pub mod withdraw_sol;
+pub mod withdraw_stake;
+pub mod withdraw_vault_stake;
 
+pub use add_will_action::*;
+pub use adopt_current_fees::*;
+pub use approve_admin_action::*;
+pub use cancel_config_change::*;
+pub use cancel_fee_schedule::*;
+pub use claim_all::*;
+pub use claim_authority::*;
 pub use claim_nft::*;
 pub use claim_sol::*;
 pub use claim_spl_token::*;
+pub use claim_stake::*;
+pub use contribute_sol::*;
+pub use contribute_spl_token::*;
+pub use create_admin_set::*;
 pub use create_will::*;
+pub use deactivate_vault_stake::*;
+pub use deposit_authority::*;
+pub use deposit_batch::*;
 pub use deposit_nft::*;
 pub use deposit_sol::*;
 pub use deposit_spl_token::*;
+pub use deposit_stake::*;
+pub use distribute_nft::*;
+pub use distribute_sol::*;
+pub use distribute_spl_token::*;
+pub use execute_admin_action::*;
+pub use execute_config_change::*;
+pub use execute_fee_schedule::*;
+pub use execute_will_action::*;
 pub use initialize::*;
+pub use migrate_config::*;
+pub use migrate_will::*;
+pub use propose_admin_action::*;
+pub use queue_config_change::*;
+pub use queue_fee_schedule::*;
+pub use reclaim_unclaimed_sol::*;
+pub use reclaim_unclaimed_spl_token::*;
+pub use refund_contribution::*;
+pub use refund_spl_contribution::*;
+pub use remove_will_action::*;
 pub use send_heartbeat::*;
+pub use set_admin_role::*;
+pub use set_config_authority::*;
+pub use set_contingent_beneficiary::*;
+pub use set_executor::*;
+pub use set_fallback::*;
+pub use set_payout_schedule::*;
+pub use set_release_date::*;
+pub use stake_vault_sol::*;
+pub use subscribe::*;
 pub use trigger_will::*;
+pub use update_admin_set::*;
+pub use update_allowlist::*;
 pub use update_config::*;
+pub use upgrade_program::*;
+pub use withdraw_authority::*;
 pub use withdraw_sol::*;
+pub use withdraw_stake::*;
+pub use withdraw_vault_stake::*;
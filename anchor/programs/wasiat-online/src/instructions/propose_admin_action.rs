@@ -0,0 +1,80 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeAdminAction<'info> {
+    /// Admin set member proposing action
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    /// Admin set - proposer must be a member
+    #[account(
+        mut,
+        seeds = [ADMIN_SET_SEED.as_bytes()],
+        bump = admin_set.bump,
+        constraint = admin_set.is_member(&proposer.key()) @ AppError::NotAdminMember,
+    )]
+    pub admin_set: Account<'info, AdminSet>,
+
+    /// Admin proposal account
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + AdminProposal::INIT_SPACE,
+        seeds = [
+            ADMIN_PROPOSAL_SEED.as_bytes(),
+            &admin_set.proposal_count.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub admin_proposal: Account<'info, AdminProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeAdminAction<'info> {
+    pub fn validate(&self, accounts: &[ActionAccountMeta], data: &[u8]) -> Result<()> {
+        // validate bounds
+        require!(
+            accounts.len() <= MAX_ACTION_ACCOUNTS,
+            AppError::TooManyActionAccounts
+        );
+        require!(
+            data.len() <= MAX_ADMIN_ACTION_DATA_LEN,
+            AppError::ActionDataTooLong
+        );
+
+        // signers besides the admin signer are co-signers, they must sign the execution
+
+        Ok(())
+    }
+}
+
+pub fn handler(
+    ctx: Context<ProposeAdminAction>,
+    accounts: Vec<ActionAccountMeta>,
+    data: Vec<u8>,
+) -> Result<()> {
+    // validate inputs
+    ctx.accounts.validate(&accounts, &data)?;
+
+    // set proposal data, proposer approves
+    let admin_set = &mut ctx.accounts.admin_set;
+    let admin_proposal = &mut ctx.accounts.admin_proposal;
+    admin_proposal.admin_set = admin_set.key();
+    admin_proposal.index = admin_set.proposal_count;
+    admin_proposal.proposer = ctx.accounts.proposer.key();
+    admin_proposal.accounts = accounts;
+    admin_proposal.data = data;
+    admin_proposal.approvals = vec![ctx.accounts.proposer.key()];
+    admin_proposal.created_at = Clock::get()?.unix_timestamp;
+    admin_proposal.bump = ctx.bumps.admin_proposal;
+
+    // update proposal counter
+    admin_set.proposal_count = admin_set
+        .proposal_count
+        .checked_add(1)
+        .ok_or(AppError::Overflow)?;
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct QueueConfigChange<'info> {
+    /// Fee manager and/or config owner queueing the change (pays pending change rent)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The program config account the change applies to
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Admin roles for fee manager validation
+    #[account(
+        seeds = [ADMIN_ROLES_SEED.as_bytes()],
+        bump = admin_roles.bump,
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+
+    /// Pending config change pda (one at a time)
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PendingConfigChange::INIT_SPACE,
+        seeds = [PENDING_CONFIG_CHANGE_SEED.as_bytes()],
+        bump,
+    )]
+    pub pending_config_change: Account<'info, PendingConfigChange>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> QueueConfigChange<'info> {
+    pub fn validate(&self, change: &ConfigChange) -> Result<()> {
+        // validate something to change
+        require!(!change.is_empty(), AppError::EmptyConfigChange);
+
+        // validate signer holds the role for each changed field
+        let authority = self.authority.key();
+        if change.changes_fees() {
+            require!(
+                self.admin_roles
+                    .has_role(AdminRole::FeeManager, &self.config, &authority),
+                AppError::Unauthorized
+            );
+        }
+        if change.changes_heartbeat_bounds() {
+            require!(self.config.authority == authority, AppError::Unauthorized);
+        }
+
+        // validate change against current config, revalidated on execution
+        let mut preview = (*self.config).clone();
+        change.apply(&mut preview)?;
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<QueueConfigChange>, change: ConfigChange) -> Result<()> {
+    // validate inputs
+    ctx.accounts.validate(&change)?;
+
+    // queue change behind the timelock
+    let current_time = Clock::get()?.unix_timestamp;
+    let pending_config_change = &mut ctx.accounts.pending_config_change;
+    pending_config_change.proposer = ctx.accounts.authority.key();
+    pending_config_change.change = change;
+    pending_config_change.queued_at = current_time;
+    pending_config_change.effective_at = current_time + CONFIG_CHANGE_DELAY as i64;
+    pending_config_change.bump = ctx.bumps.pending_config_change;
+
+    Ok(())
+}
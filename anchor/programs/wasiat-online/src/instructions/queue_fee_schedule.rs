@@ -0,0 +1,76 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct QueueFeeSchedule<'info> {
+    /// Fee manager queueing the schedule (pays pending schedule rent)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Config account for authority validation
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Admin roles for fee manager validation
+    #[account(
+        seeds = [ADMIN_ROLES_SEED.as_bytes()],
+        bump = admin_roles.bump,
+        constraint = admin_roles.has_role(AdminRole::FeeManager, &config, &authority.key()) @ AppError::Unauthorized,
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+
+    /// Pending fee schedule pda (one at a time)
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PendingFeeSchedule::INIT_SPACE,
+        seeds = [PENDING_FEE_SCHEDULE_SEED.as_bytes()],
+        bump,
+    )]
+    pub pending_fee_schedule: Account<'info, PendingFeeSchedule>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Schedules only lower fees of wills created before they take effect, new wills pay them in full,
+/// so schedules go through the timelock before applying to new wills
+pub fn handler(
+    ctx: Context<QueueFeeSchedule>,
+    sol_tiers: Vec<FeeTier>,
+    sol_min_fee: u64,
+    sol_max_fee: u64,
+    mint_fees: Vec<MintFee>,
+    annual_fee_lamports: u64,
+    referrer_share_bps: u16,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let pending_fee_schedule = &mut ctx.accounts.pending_fee_schedule;
+    pending_fee_schedule.proposer = ctx.accounts.authority.key();
+    pending_fee_schedule.sol_tiers = sol_tiers;
+    pending_fee_schedule.sol_min_fee = sol_min_fee;
+    pending_fee_schedule.sol_max_fee = sol_max_fee;
+    pending_fee_schedule.mint_fees = mint_fees;
+    pending_fee_schedule.annual_fee_lamports = annual_fee_lamports;
+    pending_fee_schedule.referrer_share_bps = referrer_share_bps;
+    pending_fee_schedule.queued_at = current_time;
+    pending_fee_schedule.effective_at = current_time + CONFIG_CHANGE_DELAY as i64;
+    pending_fee_schedule.bump = ctx.bumps.pending_fee_schedule;
+
+    // validate resulting schedule, revalidated on execution
+    let mut preview = FeeSchedule {
+        sol_tiers: Vec::new(),
+        sol_min_fee: 0,
+        sol_max_fee: 0,
+        mint_fees: Vec::new(),
+        annual_fee_lamports: 0,
+        referrer_share_bps: 0,
+        effective_at: 0,
+        bump: 0,
+    };
+    pending_fee_schedule.apply(&mut preview)?;
+
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+#[derive(Accounts)]
+pub struct ReclaimUnclaimedSol<'info> {
+    /// Keeper reclaiming unclaimed estate
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// Will account - must be triggered and left unclaimed past expiry
+    #[account(
+        mut,
+        constraint = matches!(will.status, WillStatus::Triggered | WillStatus::Expired) @ AppError::InvalidWillStatus,
+        constraint = will.staked_lamports == 0 @ AppError::VaultStakeOutstanding,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Sol vault pda (source)
+    #[account(
+        mut,
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Testator-designated fallback (destination)
+    #[account(
+        mut,
+        constraint = will.fallback == Some(fallback.key()) @ AppError::InvalidFallback,
+    )]
+    pub fallback: SystemAccount<'info>,
+
+    /// Config for fee configuration
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        constraint = !config.is_paused(PAUSE_CLAIM) @ AppError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Fee schedule (tiers and fee bounds applied to will fee)
+    #[account(
+        seeds = [FEE_SCHEDULE_SEED.as_bytes()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    /// CHECK: will referrer receiving its share of service fee (required if will has one)
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Fee vault for service fees
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED.as_bytes()],
+        bump,
+    )]
+    pub fee_vault: SystemAccount<'info>,
+
+    /// Will inventory (grown for new mints)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReclaimUnclaimedSol<'info> {
+    pub fn validate(&self) -> Result<()> {
+        // validate estate expired unclaimed
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            self.will.is_unclaimed_expired(current_time),
+            AppError::EstateNotExpired
+        );
+
+        // validate enough balance above rent exemption and contributions
+        let min_rent = Rent::get()?.minimum_balance(0);
+        require!(
+            self.reclaimable_balance(min_rent) > 0,
+            AppError::NoAssetsToClaim
+        );
+
+        Ok(())
+    }
+
+    /// Vault SOL above rent, contributions stay for contributor refunds
+    pub fn reclaimable_balance(&self, min_rent: u64) -> u64 {
+        self.vault
+            .lamports()
+            .saturating_sub(min_rent)
+            .saturating_sub(self.will.contributed_lamports)
+    }
+}
+
+pub fn handler(ctx: Context<ReclaimUnclaimedSol>) -> Result<()> {
+    // validations
+    ctx.accounts.validate()?;
+
+    // keep minimum rent in vault to prevent account closure
+    let min_rent = Rent::get()?.minimum_balance(0);
+    let reclaimable = ctx.accounts.reclaimable_balance(min_rent);
+
+    let will = &mut ctx.accounts.will;
+
+    // calculate service fee
+    let service_fee = will.sol_service_fee(
+        &ctx.accounts.config,
+        &ctx.accounts.fee_schedule,
+        reclaimable,
+    );
+    let final_reclaimable = reclaimable.saturating_sub(service_fee);
+
+    require!(final_reclaimable > 0, AppError::NoClaimableAmount);
+
+    // pda signer seeds for vault
+    let will_key = will.key();
+    let vault_seeds = &[VAULT_SEED.as_bytes(), will_key.as_ref(), &[will.vault_bump]];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    // split service fee with referrer
+    let referrer_fee = will.referrer_fee(service_fee);
+    let protocol_fee = service_fee - referrer_fee;
+
+    // transfer service fee to fee vault
+    if protocol_fee > 0 {
+        let fee_transfer_accounts = Transfer {
+            from: ctx.accounts.vault.to_account_info(),
+            to: ctx.accounts.fee_vault.to_account_info(),
+        };
+
+        let fee_transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            fee_transfer_accounts,
+            vault_signer_seeds,
+        );
+
+        transfer(fee_transfer_ctx, protocol_fee)?;
+    }
+
+    // transfer referrer share to referrer
+    if referrer_fee > 0 {
+        let Some(referrer) = ctx.accounts.referrer.as_ref() else {
+            return err!(AppError::ReferrerMismatch);
+        };
+        will.validate_referrer(referrer.key())?;
+
+        let referrer_transfer_accounts = Transfer {
+            from: ctx.accounts.vault.to_account_info(),
+            to: referrer.to_account_info(),
+        };
+
+        let referrer_transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            referrer_transfer_accounts,
+            vault_signer_seeds,
+        );
+
+        transfer(referrer_transfer_ctx, referrer_fee)?;
+    }
+
+    // transfer remaining SOL to fallback
+    let reclaim_transfer_accounts = Transfer {
+        from: ctx.accounts.vault.to_account_info(),
+        to: ctx.accounts.fallback.to_account_info(),
+    };
+
+    let reclaim_transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.system_program.to_account_info(),
+        reclaim_transfer_accounts,
+        vault_signer_seeds,
+    );
+
+    transfer(reclaim_transfer_ctx, final_reclaimable)?;
+
+    // update will status
+    will.status = WillStatus::Expired;
+
+    // update inventory
+    WillInventory::record_sol(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.keeper.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        InventoryChange::Claim(reclaimable),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,296 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer as token_transfer, Mint, Token, TokenAccount, Transfer as TokenTransfer},
+};
+
+#[derive(Accounts)]
+pub struct ReclaimUnclaimedSplToken<'info> {
+    /// Keeper reclaiming unclaimed estate (pays for missing token accounts)
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// Will account - must be triggered and left unclaimed past expiry
+    #[account(
+        mut,
+        constraint = matches!(will.status, WillStatus::Triggered | WillStatus::Expired) @ AppError::InvalidWillStatus,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Token mint (spl token or nft)
+    pub mint: Account<'info, Mint>,
+
+    /// Vault's token account (source)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        constraint = vault_token_account.amount > 0 @ AppError::NoAssetsToClaim,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Testator-designated fallback
+    #[account(
+        constraint = will.fallback == Some(fallback.key()) @ AppError::InvalidFallback,
+    )]
+    pub fallback: SystemAccount<'info>,
+
+    /// Fallback's token account (destination - create if needed)
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        associated_token::mint = mint,
+        associated_token::authority = fallback,
+    )]
+    pub fallback_token_account: Account<'info, TokenAccount>,
+
+    /// Fee vault's token account (for service fees)
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        associated_token::mint = mint,
+        associated_token::authority = fee_vault
+    )]
+    pub fee_vault_token_account: Account<'info, TokenAccount>,
+
+    /// Will referrer's token account receiving its share of token fee (required if will has one)
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub referrer_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Vault pda (authority for vault_token_account, pays nft fee in SOL)
+    #[account(
+        mut,
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Fee vault pda (authority for fee_vault_token_account, receives nft fee)
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED.as_bytes()],
+        bump,
+    )]
+    pub fee_vault: SystemAccount<'info>,
+
+    /// CHECK: will referrer receiving its share of nft fee (required if will has one)
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Config - fee configuration and validate claims not paused
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump,
+        constraint = !config.is_paused(PAUSE_CLAIM) @ AppError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Fee schedule (tiers and fee bounds applied to will fee)
+    #[account(
+        seeds = [FEE_SCHEDULE_SEED.as_bytes()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    /// Will inventory (grown for new mints)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReclaimUnclaimedSplToken<'info> {
+    pub fn validate(&self) -> Result<()> {
+        // validate estate expired unclaimed
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            self.will.is_unclaimed_expired(current_time),
+            AppError::EstateNotExpired
+        );
+
+        // validate estate left besides contributions
+        require!(self.reclaimable_balance() > 0, AppError::NoAssetsToClaim);
+
+        // validate vault SOL for nft fee
+        if AssetKind::of(&self.mint) == AssetKind::Nft {
+            let nft_fee = self.will.fees(&self.config).nft_fee_lamports;
+            let min_rent = Rent::get()?.minimum_balance(0);
+            require!(
+                self.vault.lamports() >= nft_fee.saturating_add(min_rent),
+                AppError::InsuffcientBalanceForFees
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Vault tokens excluding contributions held for refund
+    pub fn reclaimable_balance(&self) -> u64 {
+        self.vault_token_account
+            .amount
+            .saturating_sub(self.will_inventory.contributed(&self.mint.key()))
+    }
+}
+
+pub fn handler(ctx: Context<ReclaimUnclaimedSplToken>) -> Result<()> {
+    // validations
+    ctx.accounts.validate()?;
+
+    // contributions stay in vault for refund
+    let total_amount = ctx.accounts.reclaimable_balance();
+    let will = &mut ctx.accounts.will;
+
+    // calculate service fee (nfts pay the flat nft fee in SOL, tokens pay bps in kind)
+    let asset_kind = AssetKind::of(&ctx.accounts.mint);
+    let (service_fee, nft_service_fee) = match asset_kind {
+        AssetKind::Nft => (0, will.fees(&ctx.accounts.config).nft_fee_lamports),
+        _ => (
+            will.token_service_fee(
+                &ctx.accounts.config,
+                &ctx.accounts.fee_schedule,
+                &ctx.accounts.mint.key(),
+                total_amount,
+            ),
+            0,
+        ),
+    };
+    let reclaimable_amount = total_amount.saturating_sub(service_fee);
+
+    // prepare pda signer seeds
+    let will_key = will.key();
+    let vault_seeds = &[VAULT_SEED.as_bytes(), will_key.as_ref(), &[will.vault_bump]];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    // split service fees with referrer
+    let referrer_fee = will.referrer_fee(service_fee);
+    let protocol_fee = service_fee - referrer_fee;
+    let nft_referrer_fee = will.referrer_fee(nft_service_fee);
+    let nft_protocol_fee = nft_service_fee - nft_referrer_fee;
+
+    // transfer service fee to fee vault
+    if protocol_fee > 0 {
+        let fee_transfer_accounts = TokenTransfer {
+            from: ctx.accounts.vault_token_account.to_account_info(),
+            to: ctx.accounts.fee_vault_token_account.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+        };
+
+        let fee_transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            fee_transfer_accounts,
+            vault_signer_seeds,
+        );
+
+        token_transfer(fee_transfer_ctx, protocol_fee)?;
+    }
+
+    // transfer referrer share to referrer token account
+    if referrer_fee > 0 {
+        let Some(referrer_token_account) = ctx.accounts.referrer_token_account.as_ref() else {
+            return err!(AppError::ReferrerMismatch);
+        };
+        will.validate_referrer(referrer_token_account.owner)?;
+
+        let referrer_transfer_accounts = TokenTransfer {
+            from: ctx.accounts.vault_token_account.to_account_info(),
+            to: referrer_token_account.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+        };
+
+        let referrer_transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            referrer_transfer_accounts,
+            vault_signer_seeds,
+        );
+
+        token_transfer(referrer_transfer_ctx, referrer_fee)?;
+    }
+
+    // transfer nft fee from vault SOL to fee vault
+    if nft_protocol_fee > 0 {
+        let nft_fee_transfer_accounts = Transfer {
+            from: ctx.accounts.vault.to_account_info(),
+            to: ctx.accounts.fee_vault.to_account_info(),
+        };
+
+        let nft_fee_transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            nft_fee_transfer_accounts,
+            vault_signer_seeds,
+        );
+
+        transfer(nft_fee_transfer_ctx, nft_protocol_fee)?;
+    }
+
+    // transfer nft referrer share from vault SOL to referrer
+    if nft_referrer_fee > 0 {
+        let Some(referrer) = ctx.accounts.referrer.as_ref() else {
+            return err!(AppError::ReferrerMismatch);
+        };
+        will.validate_referrer(referrer.key())?;
+
+        let nft_referrer_transfer_accounts = Transfer {
+            from: ctx.accounts.vault.to_account_info(),
+            to: referrer.to_account_info(),
+        };
+
+        let nft_referrer_transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            nft_referrer_transfer_accounts,
+            vault_signer_seeds,
+        );
+
+        transfer(nft_referrer_transfer_ctx, nft_referrer_fee)?;
+    }
+
+    // record nft fee paid from vault SOL
+    if nft_service_fee > 0 {
+        WillInventory::record_sol(
+            &mut ctx.accounts.will_inventory,
+            ctx.accounts.keeper.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            InventoryChange::Claim(nft_service_fee),
+        )?;
+    }
+
+    // transfer tokens to fallback
+    let reclaim_transfer_accounts = TokenTransfer {
+        from: ctx.accounts.vault_token_account.to_account_info(),
+        to: ctx.accounts.fallback_token_account.to_account_info(),
+        authority: ctx.accounts.vault.to_account_info(),
+    };
+
+    let reclaim_transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        reclaim_transfer_accounts,
+        vault_signer_seeds,
+    );
+
+    token_transfer(reclaim_transfer_ctx, reclaimable_amount)?;
+
+    // update will status
+    will.status = WillStatus::Expired;
+
+    // update inventory
+    WillInventory::record(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.keeper.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.mint.key(),
+        asset_kind,
+        InventoryChange::Claim(total_amount),
+    )?;
+
+    Ok(())
+}
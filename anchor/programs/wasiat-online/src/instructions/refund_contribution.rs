@@ -0,0 +1,90 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::spl_token::native_mint;
+
+#[derive(Accounts)]
+pub struct RefundContribution<'info> {
+    /// Contributor reclaiming SOL contribution
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    /// Will account - must be withdrawn by testator or reclaimed to fallback
+    #[account(
+        mut,
+        constraint = matches!(will.status, WillStatus::Withdrawn | WillStatus::Expired) @ AppError::InvalidWillStatus,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Sol vault pda (source)
+    #[account(
+        mut,
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Contribution record (closed to contributor)
+    #[account(
+        mut,
+        close = contributor,
+        seeds = [
+            CONTRIBUTION_SEED.as_bytes(),
+            will.key().as_ref(),
+            contributor.key().as_ref(),
+            native_mint::ID.as_ref(),
+        ],
+        bump = contribution.bump,
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    /// Will inventory (grown for new mints)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RefundContribution>) -> Result<()> {
+    let will = &mut ctx.accounts.will;
+    let amount = ctx.accounts.contribution.amount;
+
+    // prepare pda signer seeds for vault
+    let will_key = will.key();
+    let vault_seeds = &[VAULT_SEED.as_bytes(), will_key.as_ref(), &[will.vault_bump]];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    // transfer contribution from vault back to contributor
+    let refund_transfer_accounts = Transfer {
+        from: ctx.accounts.vault.to_account_info(),
+        to: ctx.accounts.contributor.to_account_info(),
+    };
+
+    let refund_transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.system_program.to_account_info(),
+        refund_transfer_accounts,
+        vault_signer_seeds,
+    );
+
+    transfer(refund_transfer_ctx, amount)?;
+
+    // release contribution from withdrawal hold
+    will.contributed_lamports = will
+        .contributed_lamports
+        .checked_sub(amount)
+        .ok_or(AppError::Underflow)?;
+
+    // update inventory
+    WillInventory::record_sol(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.contributor.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        InventoryChange::Refund(amount),
+    )?;
+
+    Ok(())
+}
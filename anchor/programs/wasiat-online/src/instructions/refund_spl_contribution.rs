@@ -0,0 +1,112 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer as token_transfer, Mint, Token, TokenAccount, Transfer as TokenTransfer},
+};
+
+#[derive(Accounts)]
+pub struct RefundSplContribution<'info> {
+    /// Contributor reclaiming spl token contribution
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    /// Will account - must be withdrawn by testator or reclaimed to fallback
+    #[account(
+        constraint = matches!(will.status, WillStatus::Withdrawn | WillStatus::Expired) @ AppError::InvalidWillStatus,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Token mint
+    pub mint: Account<'info, Mint>,
+
+    /// Vault's token account (source)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Contributor's token account (destination - create if needed)
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        associated_token::mint = mint,
+        associated_token::authority = contributor,
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+
+    /// Vault pda (authority for vault_token_account)
+    #[account(
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Contribution record (closed to contributor)
+    #[account(
+        mut,
+        close = contributor,
+        seeds = [
+            CONTRIBUTION_SEED.as_bytes(),
+            will.key().as_ref(),
+            contributor.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump = contribution.bump,
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    /// Will inventory (holds contributions per mint)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RefundSplContribution>) -> Result<()> {
+    let amount = ctx.accounts.contribution.amount;
+
+    // prepare pda signer seeds for vault
+    let will_key = ctx.accounts.will.key();
+    let vault_seeds = &[
+        VAULT_SEED.as_bytes(),
+        will_key.as_ref(),
+        &[ctx.accounts.will.vault_bump],
+    ];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    // transfer contribution from vault back to contributor
+    let refund_transfer_accounts = TokenTransfer {
+        from: ctx.accounts.vault_token_account.to_account_info(),
+        to: ctx.accounts.contributor_token_account.to_account_info(),
+        authority: ctx.accounts.vault.to_account_info(),
+    };
+
+    let refund_transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        refund_transfer_accounts,
+        vault_signer_seeds,
+    );
+
+    token_transfer(refund_transfer_ctx, amount)?;
+
+    // update inventory (entry the contribution was recorded under)
+    WillInventory::record(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.contributor.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.mint.key(),
+        ctx.accounts.contribution.kind,
+        InventoryChange::Refund(amount),
+    )?;
+
+    Ok(())
+}
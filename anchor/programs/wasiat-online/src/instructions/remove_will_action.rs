@@ -0,0 +1,49 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveWillAction<'info> {
+    /// Testator removing action
+    #[account(mut)]
+    pub testator: Signer<'info>,
+
+    /// Will account - must be owned by testator
+    #[account(
+        mut,
+        seeds = [
+            WILL_SEED.as_bytes(),
+            testator.key().as_ref(),
+            will.beneficiary.as_ref()
+        ],
+        bump = will.bump,
+        constraint = will.testator == testator.key() @ AppError::Unauthorized,
+        constraint = matches!(will.status, WillStatus::Created | WillStatus::Active) @ AppError::InvalidWillStatus,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Will action account (closed, rent back to testator)
+    #[account(
+        mut,
+        seeds = [
+            WILL_ACTION_SEED.as_bytes(),
+            will.key().as_ref(),
+            &[will_action.index],
+        ],
+        bump = will_action.bump,
+        has_one = will,
+        close = testator,
+    )]
+    pub will_action: Account<'info, WillAction>,
+}
+
+pub fn handler(ctx: Context<RemoveWillAction>) -> Result<()> {
+    let will = &mut ctx.accounts.will;
+
+    // update will action counter
+    will.action_count = will
+        .action_count
+        .checked_sub(1)
+        .ok_or(AppError::Underflow)?;
+
+    Ok(())
+}
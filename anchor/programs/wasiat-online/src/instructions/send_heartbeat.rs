@@ -1,9 +1,11 @@
 use crate::{constants::*, error::AppError, state::*};
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
 
 #[derive(Accounts)]
 pub struct SendHeartbeat<'info> {
-    /// Testator sending heartbeat
+    /// Testator sending heartbeat (pays subscription fee if subscribed)
+    #[account(mut)]
     pub testator: Signer<'info>,
 
     /// Config account for validation
@@ -26,6 +28,16 @@ pub struct SendHeartbeat<'info> {
         constraint = will.status == WillStatus::Active @ AppError::InvalidWillStatus,
     )]
     pub will: Account<'info, Will>,
+
+    /// Fee vault for subscription fees
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED.as_bytes()],
+        bump,
+    )]
+    pub fee_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 impl<'info> SendHeartbeat<'info> {
@@ -38,12 +50,15 @@ impl<'info> SendHeartbeat<'info> {
         let time_since_last = current_time - will.last_heartbeat;
         require!(
             time_since_last >= config.min_heartbeat_interval as i64,
-            AppError::HeartbeatPeriodTooShort
+            AppError::HeartbeatTooFrequent
         );
 
         // validate if heartbeat is still meaningful (not expired)
-        let expiry_time = will.last_heartbeat + will.heartbeat_period as i64;
-        require!(current_time < expiry_time, AppError::WillAlreadyExpired);
+        let paused_seconds = config.paused_seconds(current_time);
+        require!(
+            current_time < will.expiry_time(paused_seconds),
+            AppError::WillAlreadyExpired
+        );
 
         Ok(())
     }
@@ -53,11 +68,31 @@ pub fn handler(ctx: Context<SendHeartbeat>) -> Result<()> {
     // validate inputs
     ctx.accounts.validate()?;
 
-    let will = &mut ctx.accounts.will;
     let clock = Clock::get()?;
 
+    // charge subscription fee since last payment
+    if ctx.accounts.will.is_subscribed() {
+        let subscription_fee = ctx.accounts.will.renew_subscription(clock.unix_timestamp);
+        if subscription_fee > 0 {
+            let fee_transfer_accounts = Transfer {
+                from: ctx.accounts.testator.to_account_info(),
+                to: ctx.accounts.fee_vault.to_account_info(),
+            };
+
+            let fee_transfer_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                fee_transfer_accounts,
+            );
+
+            transfer(fee_transfer_ctx, subscription_fee)?;
+        }
+    }
+
+    let paused_seconds = ctx.accounts.config.paused_seconds(clock.unix_timestamp);
+    let will = &mut ctx.accounts.will;
+
     // update heartbeat timestamp
-    will.last_heartbeat = clock.unix_timestamp;
+    will.record_heartbeat(clock.unix_timestamp, paused_seconds);
 
     Ok(())
 }
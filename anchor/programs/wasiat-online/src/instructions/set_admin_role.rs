@@ -0,0 +1,42 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetAdminRole<'info> {
+    /// Config owner assigning roles
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Config account for authority validation
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ AppError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Admin roles (create if needed, for configs initialized before roles)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AdminRoles::INIT_SPACE,
+        seeds = [ADMIN_ROLES_SEED.as_bytes()],
+        bump,
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Assigns `role` to `holder`, or revokes it back to the config owner with None
+pub fn handler(ctx: Context<SetAdminRole>, role: AdminRole, holder: Option<Pubkey>) -> Result<()> {
+    let admin_roles = &mut ctx.accounts.admin_roles;
+    match role {
+        AdminRole::Pauser => admin_roles.pauser = holder,
+        AdminRole::FeeManager => admin_roles.fee_manager = holder,
+        AdminRole::Upgrader => admin_roles.upgrader = holder,
+    }
+    admin_roles.bump = ctx.bumps.admin_roles;
+
+    Ok(())
+}
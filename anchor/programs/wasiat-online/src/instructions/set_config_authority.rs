@@ -0,0 +1,29 @@
+use crate::{constants::*, error::AppError, state::Config};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetConfigAuthority<'info> {
+    /// Current authority handing over config
+    pub authority: Signer<'info>,
+
+    /// New authority accepting config (pda authorities sign through their program)
+    pub new_authority: Signer<'info>,
+
+    /// The program config account
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ AppError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// New authority may be a wallet, an external multisig pda or the admin set signer pda,
+/// its signature proves config is not handed to a key nobody controls
+pub fn handler(ctx: Context<SetConfigAuthority>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.authority = ctx.accounts.new_authority.key();
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetContingentBeneficiary<'info> {
+    /// Testator setting contingent beneficiary
+    pub testator: Signer<'info>,
+
+    /// Will account - must be owned by testator and not yet triggered
+    #[account(
+        mut,
+        seeds = [
+            WILL_SEED.as_bytes(),
+            testator.key().as_ref(),
+            will.beneficiary.as_ref()
+        ],
+        bump = will.bump,
+        constraint = will.testator == testator.key() @ AppError::Unauthorized,
+        constraint = matches!(will.status, WillStatus::Created | WillStatus::Active) @ AppError::InvalidWillStatus,
+    )]
+    pub will: Account<'info, Will>,
+}
+
+impl<'info> SetContingentBeneficiary<'info> {
+    pub fn validate(
+        &self,
+        contingent_beneficiary: Option<Pubkey>,
+        claim_deadline: u32,
+    ) -> Result<()> {
+        let will = &self.will;
+
+        if let Some(contingent_beneficiary) = contingent_beneficiary {
+            // validate contingent beneficiary is a distinct party
+            require!(
+                contingent_beneficiary != will.testator
+                    && contingent_beneficiary != will.beneficiary,
+                AppError::InvalidContingentBeneficiary
+            );
+
+            // validate primary beneficiary gets a claim window
+            require!(claim_deadline > 0, AppError::InvalidClaimDeadline);
+
+            // validate contingent beneficiary can claim before estate expires
+            if will.fallback.is_some() {
+                require!(
+                    claim_deadline < will.unclaimed_expiry,
+                    AppError::InvalidUnclaimedExpiry
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn handler(
+    ctx: Context<SetContingentBeneficiary>,
+    contingent_beneficiary: Option<Pubkey>,
+    claim_deadline: u32,
+) -> Result<()> {
+    // validate inputs
+    ctx.accounts
+        .validate(contingent_beneficiary, claim_deadline)?;
+
+    let will = &mut ctx.accounts.will;
+
+    // set contingent beneficiary (none clears it)
+    will.contingent_beneficiary = contingent_beneficiary;
+    will.claim_deadline = if contingent_beneficiary.is_some() {
+        claim_deadline
+    } else {
+        0
+    };
+
+    Ok(())
+}
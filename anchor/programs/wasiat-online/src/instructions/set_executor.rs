@@ -0,0 +1,60 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetExecutor<'info> {
+    /// Testator setting executor
+    pub testator: Signer<'info>,
+
+    /// Will account - must be owned by testator and not yet triggered
+    #[account(
+        mut,
+        seeds = [
+            WILL_SEED.as_bytes(),
+            testator.key().as_ref(),
+            will.beneficiary.as_ref()
+        ],
+        bump = will.bump,
+        constraint = will.testator == testator.key() @ AppError::Unauthorized,
+        constraint = matches!(will.status, WillStatus::Created | WillStatus::Active) @ AppError::InvalidWillStatus,
+    )]
+    pub will: Account<'info, Will>,
+}
+
+impl<'info> SetExecutor<'info> {
+    pub fn validate(&self, executor: Option<Pubkey>, executor_fee_bps: u16) -> Result<()> {
+        if let Some(executor) = executor {
+            // validate executor is not the testator
+            require!(executor != self.will.testator, AppError::InvalidExecutor);
+
+            // validate executor compensation
+            require!(
+                executor_fee_bps <= MAX_EXECUTOR_FEE_BPS,
+                AppError::InvalidExecutorFee
+            );
+        }
+
+        Ok(())
+    }
+}
+
+pub fn handler(
+    ctx: Context<SetExecutor>,
+    executor: Option<Pubkey>,
+    executor_fee_bps: u16,
+) -> Result<()> {
+    // validate inputs
+    ctx.accounts.validate(executor, executor_fee_bps)?;
+
+    let will = &mut ctx.accounts.will;
+
+    // set executor (none removes it)
+    will.executor = executor;
+    will.executor_fee_bps = if executor.is_some() {
+        executor_fee_bps
+    } else {
+        0
+    };
+
+    Ok(())
+}
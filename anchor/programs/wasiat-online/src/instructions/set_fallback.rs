@@ -0,0 +1,68 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetFallback<'info> {
+    /// Testator setting fallback
+    pub testator: Signer<'info>,
+
+    /// Will account - must be owned by testator and not yet triggered
+    #[account(
+        mut,
+        seeds = [
+            WILL_SEED.as_bytes(),
+            testator.key().as_ref(),
+            will.beneficiary.as_ref()
+        ],
+        bump = will.bump,
+        constraint = will.testator == testator.key() @ AppError::Unauthorized,
+        constraint = matches!(will.status, WillStatus::Created | WillStatus::Active) @ AppError::InvalidWillStatus,
+    )]
+    pub will: Account<'info, Will>,
+}
+
+impl<'info> SetFallback<'info> {
+    pub fn validate(&self, fallback: Option<Pubkey>, unclaimed_expiry: u32) -> Result<()> {
+        let will = &self.will;
+
+        if let Some(fallback) = fallback {
+            // validate fallback is not the beneficiary
+            require!(fallback != will.beneficiary, AppError::InvalidFallback);
+
+            // validate beneficiaries get a claim window before expiry
+            require!(
+                unclaimed_expiry > will.claim_deadline,
+                AppError::InvalidUnclaimedExpiry
+            );
+
+            // validate estate fully vests before it can expire unclaimed
+            require!(
+                unclaimed_expiry > will.payout_schedule.duration(),
+                AppError::InvalidUnclaimedExpiry
+            );
+        }
+
+        Ok(())
+    }
+}
+
+pub fn handler(
+    ctx: Context<SetFallback>,
+    fallback: Option<Pubkey>,
+    unclaimed_expiry: u32,
+) -> Result<()> {
+    // validate inputs
+    ctx.accounts.validate(fallback, unclaimed_expiry)?;
+
+    let will = &mut ctx.accounts.will;
+
+    // set fallback (none disables reclaim)
+    will.fallback = fallback;
+    will.unclaimed_expiry = if fallback.is_some() {
+        unclaimed_expiry
+    } else {
+        0
+    };
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPayoutSchedule<'info> {
+    /// Testator setting payout schedule
+    pub testator: Signer<'info>,
+
+    /// Will account - must be owned by testator and not yet triggered
+    #[account(
+        mut,
+        seeds = [
+            WILL_SEED.as_bytes(),
+            testator.key().as_ref(),
+            will.beneficiary.as_ref()
+        ],
+        bump = will.bump,
+        constraint = will.testator == testator.key() @ AppError::Unauthorized,
+        constraint = matches!(will.status, WillStatus::Created | WillStatus::Active) @ AppError::InvalidWillStatus,
+    )]
+    pub will: Account<'info, Will>,
+}
+
+impl<'info> SetPayoutSchedule<'info> {
+    pub fn validate(&self, payout_schedule: &PayoutSchedule) -> Result<()> {
+        // validate schedule parameters
+        payout_schedule.validate()?;
+
+        // validate estate fully vests before it can expire unclaimed
+        if self.will.fallback.is_some() {
+            require!(
+                payout_schedule.duration() < self.will.unclaimed_expiry,
+                AppError::InvalidUnclaimedExpiry
+            );
+        }
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<SetPayoutSchedule>, payout_schedule: PayoutSchedule) -> Result<()> {
+    // validate inputs
+    ctx.accounts.validate(&payout_schedule)?;
+
+    // set payout schedule
+    let will = &mut ctx.accounts.will;
+    will.payout_schedule = payout_schedule;
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetReleaseDate<'info> {
+    /// Testator setting release date
+    pub testator: Signer<'info>,
+
+    /// Will account - must be owned by testator and not yet triggered
+    #[account(
+        mut,
+        seeds = [
+            WILL_SEED.as_bytes(),
+            testator.key().as_ref(),
+            will.beneficiary.as_ref()
+        ],
+        bump = will.bump,
+        constraint = will.testator == testator.key() @ AppError::Unauthorized,
+        constraint = matches!(will.status, WillStatus::Created | WillStatus::Active) @ AppError::InvalidWillStatus,
+    )]
+    pub will: Account<'info, Will>,
+}
+
+impl<'info> SetReleaseDate<'info> {
+    pub fn validate(&self, release_not_before: Option<i64>) -> Result<()> {
+        // validate release date is in the future
+        if let Some(release_not_before) = release_not_before {
+            let current_time = Clock::get()?.unix_timestamp;
+            require!(
+                release_not_before > current_time,
+                AppError::InvalidReleaseDate
+            );
+        }
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<SetReleaseDate>, release_not_before: Option<i64>) -> Result<()> {
+    // validate inputs
+    ctx.accounts.validate(release_not_before)?;
+
+    // set release date (none releases at trigger)
+    let will = &mut ctx.accounts.will;
+    will.release_not_before = release_not_before;
+
+    Ok(())
+}
@@ -0,0 +1,199 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        program::{invoke, invoke_signed},
+        stake::{
+            instruction::{delegate_stake, initialize},
+            state::{Authorized, Lockup, StakeStateV2},
+        },
+        sysvar::stake_history,
+    },
+    system_program::{allocate, assign, transfer, Allocate, Assign, Transfer},
+};
+use anchor_spl::stake::Stake;
+
+#[derive(Accounts)]
+pub struct StakeVaultSol<'info> {
+    /// Testator staking vault SOL
+    #[account(mut)]
+    pub testator: Signer<'info>,
+
+    /// Will account - must be owned by testator and in active status
+    #[account(
+        mut,
+        seeds = [
+            WILL_SEED.as_bytes(),
+            testator.key().as_ref(),
+            will.beneficiary.as_ref()
+        ],
+        bump = will.bump,
+        constraint = will.testator == testator.key() @ AppError::Unauthorized,
+        constraint = will.status == WillStatus::Active @ AppError::InvalidWillStatus,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Sol vault pda (source, staker and withdrawer authority)
+    #[account(
+        mut,
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Vault stake account pda (created here, may already hold lamports sent by anyone)
+    #[account(
+        mut,
+        seeds = [VAULT_STAKE_SEED.as_bytes(), will.key().as_ref()],
+        bump,
+    )]
+    pub vault_stake: SystemAccount<'info>,
+
+    /// CHECK: validator vote account, validated by the stake program
+    pub vote_account: UncheckedAccount<'info>,
+
+    /// CHECK: legacy stake config account, unused but required by the stake program
+    pub stake_config: UncheckedAccount<'info>,
+
+    /// CHECK: stake history sysvar
+    #[account(address = stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub stake_program: Program<'info, Stake>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> StakeVaultSol<'info> {
+    pub fn validate(&self, amount: u64) -> Result<()> {
+        // validate amount
+        require!(amount > 0, AppError::InvalidAmount);
+
+        // validate only one vault stake at a time
+        require!(self.will.staked_lamports == 0, AppError::VaultAlreadyStaked);
+
+        // validate stake account rent exemption
+        let stake_rent = self.rent.minimum_balance(StakeStateV2::size_of());
+        require!(amount > stake_rent, AppError::StakeAmountTooSmall);
+
+        // validate vault keeps rent exemption after staking
+        let vault_balance = self.vault.lamports();
+        let min_rent = self.rent.minimum_balance(0);
+        require!(
+            vault_balance >= amount + min_rent,
+            AppError::InsufficientBalance
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<StakeVaultSol>, amount: u64) -> Result<()> {
+    // validate inputs
+    ctx.accounts.validate(amount)?;
+
+    // prepare pda signer seeds for vault and vault stake
+    let will_key = ctx.accounts.will.key();
+    let vault_seeds = &[
+        VAULT_SEED.as_bytes(),
+        will_key.as_ref(),
+        &[ctx.accounts.will.vault_bump],
+    ];
+    let vault_stake_seeds = &[
+        VAULT_STAKE_SEED.as_bytes(),
+        will_key.as_ref(),
+        &[ctx.bumps.vault_stake],
+    ];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+    let vault_stake_signer_seeds = &[&vault_stake_seeds[..]];
+
+    // fund vault stake account from vault, only the shortfall if lamports were sent beforehand
+    // (create_account would fail on a funded address)
+    let vault_stake_info = ctx.accounts.vault_stake.to_account_info();
+    let shortfall = amount.saturating_sub(vault_stake_info.lamports());
+    if shortfall > 0 {
+        let fund_transfer_accounts = Transfer {
+            from: ctx.accounts.vault.to_account_info(),
+            to: vault_stake_info.clone(),
+        };
+
+        let fund_transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            fund_transfer_accounts,
+            vault_signer_seeds,
+        );
+
+        transfer(fund_transfer_ctx, shortfall)?;
+    }
+
+    // allocate stake state and hand vault stake account to stake program
+    let allocate_accounts = Allocate {
+        account_to_allocate: vault_stake_info.clone(),
+    };
+
+    let allocate_ctx = CpiContext::new_with_signer(
+        ctx.accounts.system_program.to_account_info(),
+        allocate_accounts,
+        vault_stake_signer_seeds,
+    );
+
+    allocate(allocate_ctx, StakeStateV2::size_of() as u64)?;
+
+    let assign_accounts = Assign {
+        account_to_assign: vault_stake_info.clone(),
+    };
+
+    let assign_ctx = CpiContext::new_with_signer(
+        ctx.accounts.system_program.to_account_info(),
+        assign_accounts,
+        vault_stake_signer_seeds,
+    );
+
+    assign(assign_ctx, &ctx.accounts.stake_program.key())?;
+
+    // initialize stake account with vault as staker and withdrawer
+    let vault_key = ctx.accounts.vault.key();
+    let authorized = Authorized {
+        staker: vault_key,
+        withdrawer: vault_key,
+    };
+
+    invoke(
+        &initialize(
+            &ctx.accounts.vault_stake.key(),
+            &authorized,
+            &Lockup::default(),
+        ),
+        &[
+            ctx.accounts.vault_stake.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+            ctx.accounts.stake_program.to_account_info(),
+        ],
+    )?;
+
+    // delegate to chosen validator
+    invoke_signed(
+        &delegate_stake(
+            &ctx.accounts.vault_stake.key(),
+            &vault_key,
+            &ctx.accounts.vote_account.key(),
+        ),
+        &[
+            ctx.accounts.vault_stake.to_account_info(),
+            ctx.accounts.vote_account.to_account_info(),
+            ctx.accounts.clock.to_account_info(),
+            ctx.accounts.stake_history.to_account_info(),
+            ctx.accounts.stake_config.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            ctx.accounts.stake_program.to_account_info(),
+        ],
+        vault_signer_seeds,
+    )?;
+
+    // record staked principal (incl. lamports sent to the address beforehand)
+    let will = &mut ctx.accounts.will;
+    will.staked_lamports = vault_stake_info.lamports();
+
+    Ok(())
+}
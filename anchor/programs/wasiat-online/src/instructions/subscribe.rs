@@ -0,0 +1,83 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+#[derive(Accounts)]
+pub struct Subscribe<'info> {
+    /// Testator switching will to the subscription fee (pays first year upfront)
+    #[account(mut)]
+    pub testator: Signer<'info>,
+
+    /// Will account - must be owned by testator and still empty
+    #[account(
+        mut,
+        seeds = [
+            WILL_SEED.as_bytes(),
+            testator.key().as_ref(),
+            will.beneficiary.as_ref()
+        ],
+        bump = will.bump,
+        constraint = will.testator == testator.key() @ AppError::Unauthorized,
+        constraint = will.status == WillStatus::Created @ AppError::InvalidWillStatus,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Fee schedule offering the subscription
+    #[account(
+        seeds = [FEE_SCHEDULE_SEED.as_bytes()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    /// Fee vault for subscription fees
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED.as_bytes()],
+        bump,
+    )]
+    pub fee_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Subscribe<'info> {
+    pub fn validate(&self) -> Result<()> {
+        // validate subscription offered
+        require!(
+            self.fee_schedule.annual_fee_lamports > 0,
+            AppError::SubscriptionUnavailable
+        );
+
+        // validate fee model chosen once
+        require!(!self.will.is_subscribed(), AppError::AlreadySubscribed);
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<Subscribe>) -> Result<()> {
+    // validations
+    ctx.accounts.validate()?;
+
+    // transfer first year from testator to fee vault
+    let annual_fee_lamports = ctx.accounts.fee_schedule.annual_fee_lamports;
+    let fee_transfer_accounts = Transfer {
+        from: ctx.accounts.testator.to_account_info(),
+        to: ctx.accounts.fee_vault.to_account_info(),
+    };
+
+    let fee_transfer_ctx = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        fee_transfer_accounts,
+    );
+
+    transfer(fee_transfer_ctx, annual_fee_lamports)?;
+
+    // lock annual fee, later years charged pro-rata on heartbeat
+    let current_time = Clock::get()?.unix_timestamp;
+    let will = &mut ctx.accounts.will;
+    will.subscription_fee_lamports = annual_fee_lamports;
+    will.subscription_paid_until = current_time + SECONDS_PER_YEAR as i64;
+
+    Ok(())
+}
@@ -18,9 +18,16 @@ pub struct TriggerWill<'info> {
     #[account(
         seeds = [CONFIG_SEED.as_bytes()],
         bump,
-        constraint = !config.paused @ AppError::ProgramPaused,
+        constraint = !config.is_paused(PAUSE_TRIGGER) @ AppError::ProgramPaused,
     )]
     pub config: Account<'info, Config>,
+
+    /// Vault pda - SOL balance snapshotted for payout schedule
+    #[account(
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
 }
 
 impl<'info> TriggerWill<'info> {
@@ -29,7 +36,7 @@ impl<'info> TriggerWill<'info> {
         let current_time = Clock::get()?.unix_timestamp;
 
         // validate if heartbeat period has actuallt expired
-        let expiry_time = will.last_heartbeat + will.heartbeat_period as i64;
+        let expiry_time = will.expiry_time(self.config.paused_seconds(current_time));
         require!(current_time >= expiry_time, AppError::WillNotExpired);
 
         // validate grace period to prevent edgse cases
@@ -53,5 +60,15 @@ pub fn handler(ctx: Context<TriggerWill>) -> Result<()> {
     will.status = WillStatus::Triggered;
     will.trigger_at = Some(clock.unix_timestamp);
 
+    // snapshot SOL vesting total (incl. vault stake) so later inflows don't speed up payouts
+    let min_rent = Rent::get()?.minimum_balance(0);
+    will.sol_vesting_total = ctx
+        .accounts
+        .vault
+        .lamports()
+        .saturating_sub(min_rent)
+        .checked_add(will.staked_lamports)
+        .ok_or(AppError::Overflow)?;
+
     Ok(())
 }
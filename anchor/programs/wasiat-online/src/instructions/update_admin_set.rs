@@ -0,0 +1,32 @@
+use crate::{constants::*, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateAdminSet<'info> {
+    /// Admin signer pda, only signs through an executed admin proposal
+    #[account(
+        seeds = [ADMIN_SIGNER_SEED.as_bytes()],
+        bump = admin_set.signer_bump,
+    )]
+    pub admin_signer: Signer<'info>,
+
+    /// Admin set account to be updated
+    #[account(
+        mut,
+        seeds = [ADMIN_SET_SEED.as_bytes()],
+        bump = admin_set.bump,
+    )]
+    pub admin_set: Account<'info, AdminSet>,
+}
+
+pub fn handler(ctx: Context<UpdateAdminSet>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    // validate inputs
+    AdminSet::validate_members(&members, threshold)?;
+
+    // replace members (approvals of removed members stop counting)
+    let admin_set = &mut ctx.accounts.admin_set;
+    admin_set.members = members;
+    admin_set.threshold = threshold;
+
+    Ok(())
+}
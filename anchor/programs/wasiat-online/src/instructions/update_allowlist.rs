@@ -0,0 +1,50 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateAllowlist<'info> {
+    /// Authority that can update the allowlist
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Config account for authority validation
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ AppError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Program allowlist (create if needed)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProgramAllowlist::INIT_SPACE,
+        seeds = [PROGRAM_ALLOWLIST_SEED.as_bytes()],
+        bump,
+    )]
+    pub program_allowlist: Account<'info, ProgramAllowlist>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UpdateAllowlist>, program_id: Pubkey, allowed: bool) -> Result<()> {
+    let program_allowlist = &mut ctx.accounts.program_allowlist;
+    program_allowlist.bump = ctx.bumps.program_allowlist;
+
+    if allowed {
+        if !program_allowlist.is_allowed(&program_id) {
+            require!(
+                program_allowlist.programs.len() < MAX_ALLOWED_PROGRAMS,
+                AppError::AllowlistFull
+            );
+            program_allowlist.programs.push(program_id);
+        }
+    } else {
+        program_allowlist
+            .programs
+            .retain(|program| *program != program_id);
+    }
+
+    Ok(())
+}
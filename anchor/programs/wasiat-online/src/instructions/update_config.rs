@@ -1,9 +1,9 @@
-use crate::{constants::*, error::AppError, state::Config};
+use crate::{constants::*, error::AppError, state::*};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct UpdateConfig<'info> {
-    /// Authority that can update config
+    /// Pauser that can update pause flags
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -12,59 +12,24 @@ pub struct UpdateConfig<'info> {
         mut,
         seeds = [CONFIG_SEED.as_bytes()],
         bump = config.bump,
-        constraint = config.authority == authority.key() @ AppError::Unauthorized,
     )]
     pub config: Account<'info, Config>,
+
+    /// Admin roles for pauser validation
+    #[account(
+        seeds = [ADMIN_ROLES_SEED.as_bytes()],
+        bump = admin_roles.bump,
+        constraint = admin_roles.has_role(AdminRole::Pauser, &config, &authority.key()) @ AppError::Unauthorized,
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
 }
 
-pub fn handler(
-    ctx: Context<UpdateConfig>,
-    token_fee_bps: Option<u16>,
-    nft_fee_lamports: Option<u64>,
-    min_heartbeat_period: Option<u32>,
-    max_heartbeat_period: Option<u32>,
-    min_heartbeat_interval: Option<u32>,
-    paused: Option<bool>,
-) -> Result<()> {
+/// Fee and heartbeat bounds go through queue_config_change, only pausing applies instantly
+pub fn handler(ctx: Context<UpdateConfig>, pause_flags: u8) -> Result<()> {
     let config = &mut ctx.accounts.config;
 
-    // Update fields if provided
-    if let Some(token_fee_bps) = token_fee_bps {
-        config.token_fee_bps = token_fee_bps;
-    }
-
-    if let Some(nft_fee_lamports) = nft_fee_lamports {
-        config.nft_fee_lamports = nft_fee_lamports;
-    }
-
-    if let Some(min_heartbeat_period) = min_heartbeat_period {
-        require!(
-            min_heartbeat_period > 0,
-            AppError::InvalidMinimumHeartbeatPeriod
-        );
-        config.min_heartbeat_period = min_heartbeat_period;
-    }
-
-    if let Some(max_heartbeat_period) = max_heartbeat_period {
-        let min_period = config.min_heartbeat_period;
-        require!(
-            max_heartbeat_period > min_period,
-            AppError::InvalidMaximumHeartbeatPeriod
-        );
-        config.max_heartbeat_period = max_heartbeat_period;
-    }
-
-    if let Some(min_heartbeat_interval) = min_heartbeat_interval {
-        require!(
-            min_heartbeat_interval > 0,
-            AppError::InvalidMinimumHeartbeatInterval
-        );
-        config.min_heartbeat_interval = min_heartbeat_interval;
-    }
-
-    if let Some(paused) = paused {
-        config.paused = paused;
-    }
+    let current_time = Clock::get()?.unix_timestamp;
+    config.set_pause_flags(pause_flags, current_time)?;
 
     Ok(())
 }
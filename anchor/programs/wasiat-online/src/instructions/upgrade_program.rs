@@ -0,0 +1,122 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+#[allow(deprecated)]
+use anchor_lang::solana_program::{
+    bpf_loader_upgradeable::ID as BPF_LOADER_UPGRADEABLE_ID,
+    instruction::{AccountMeta, Instruction},
+    loader_upgradeable_instruction::UpgradeableLoaderInstruction,
+    program::invoke_signed,
+};
+
+#[derive(Accounts)]
+pub struct UpgradeProgram<'info> {
+    /// Upgrader deploying the new program binary
+    pub authority: Signer<'info>,
+
+    /// Config account (upgrader defaults to config owner)
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Admin roles for upgrader validation
+    #[account(
+        seeds = [ADMIN_ROLES_SEED.as_bytes()],
+        bump = admin_roles.bump,
+        constraint = admin_roles.has_role(AdminRole::Upgrader, &config, &authority.key()) @ AppError::Unauthorized,
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+
+    /// CHECK: this program, upgraded in place
+    #[account(
+        mut,
+        address = crate::ID,
+    )]
+    pub program: UncheckedAccount<'info>,
+
+    /// CHECK: this program's data account, upgrade authority validated in handler
+    #[account(mut)]
+    pub program_data: UncheckedAccount<'info>,
+
+    /// CHECK: buffer holding the new program binary, validated by the loader
+    #[account(mut)]
+    pub buffer: UncheckedAccount<'info>,
+
+    /// CHECK: receives the buffer's lamports after upgrade
+    #[account(mut)]
+    pub spill: UncheckedAccount<'info>,
+
+    /// Upgrade authority pda (program upgrade authority handed to it once)
+    #[account(
+        seeds = [UPGRADE_AUTHORITY_SEED.as_bytes()],
+        bump,
+    )]
+    pub upgrade_authority: SystemAccount<'info>,
+
+    /// CHECK: bpf upgradeable loader
+    #[account(address = BPF_LOADER_UPGRADEABLE_ID)]
+    pub bpf_loader: UncheckedAccount<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> UpgradeProgram<'info> {
+    pub fn validate(&self) -> Result<()> {
+        // validate upgrade authority was handed to the pda
+        let current_authority = AuthorityKind::ProgramUpgrade
+            .current_authority(&self.program_data, &self.bpf_loader)?;
+        require!(
+            current_authority == Some(self.upgrade_authority.key()),
+            AppError::UpgradeAuthorityNotHeld
+        );
+
+        Ok(())
+    }
+}
+
+/// Upgrades the program from `buffer`, signing as the upgrade authority pda
+pub fn handler(ctx: Context<UpgradeProgram>) -> Result<()> {
+    // validations
+    ctx.accounts.validate()?;
+
+    // prepare pda signer seeds for upgrade authority
+    let upgrade_authority_seeds = &[
+        UPGRADE_AUTHORITY_SEED.as_bytes(),
+        &[ctx.bumps.upgrade_authority],
+    ];
+    let upgrade_authority_signer_seeds = &[&upgrade_authority_seeds[..]];
+
+    // upgrade program (loader validates program data and buffer)
+    let upgrade_ix = Instruction::new_with_bincode(
+        BPF_LOADER_UPGRADEABLE_ID,
+        &UpgradeableLoaderInstruction::Upgrade,
+        vec![
+            AccountMeta::new(ctx.accounts.program_data.key(), false),
+            AccountMeta::new(ctx.accounts.program.key(), false),
+            AccountMeta::new(ctx.accounts.buffer.key(), false),
+            AccountMeta::new(ctx.accounts.spill.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.rent.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.clock.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.upgrade_authority.key(), true),
+        ],
+    );
+
+    invoke_signed(
+        &upgrade_ix,
+        &[
+            ctx.accounts.program_data.to_account_info(),
+            ctx.accounts.program.to_account_info(),
+            ctx.accounts.buffer.to_account_info(),
+            ctx.accounts.spill.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+            ctx.accounts.clock.to_account_info(),
+            ctx.accounts.upgrade_authority.to_account_info(),
+            ctx.accounts.bpf_loader.to_account_info(),
+        ],
+        upgrade_authority_signer_seeds,
+    )?;
+
+    Ok(())
+}
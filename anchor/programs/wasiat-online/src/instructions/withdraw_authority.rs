@@ -0,0 +1,88 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct WithdrawAuthority<'info> {
+    /// Testator taking back authority (pays inventory growth)
+    #[account(mut)]
+    pub testator: Signer<'info>,
+
+    /// Will account - must be owned by testator and NOT triggered/claimed
+    #[account(
+        constraint = will.testator == testator.key() @ AppError::Unauthorized,
+        constraint = matches!(will.status, WillStatus::Created | WillStatus::Active | WillStatus::Withdrawn) @ AppError::CannotWithdrawAfterTriggered,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// CHECK: program data, mint, token account or multisig whose authority is returned, validated per authority kind
+    #[account(mut)]
+    pub target: UncheckedAccount<'info>,
+
+    /// CHECK: program owning target (upgradeable loader, token program or squads), validated per authority kind
+    pub target_program: UncheckedAccount<'info>,
+
+    /// Vault pda (current authority)
+    #[account(
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Will inventory (grown for new authorities)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawAuthority<'info> {
+    pub fn validate(&self, kind: AuthorityKind) -> Result<()> {
+        // validate vault holds the authority
+        let current_authority = kind.current_authority(&self.target, &self.target_program)?;
+        require!(
+            current_authority == Some(self.vault.key()),
+            AppError::NoAssetsToWithdraw
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<WithdrawAuthority>, kind: AuthorityKind) -> Result<()> {
+    // validations
+    ctx.accounts.validate(kind)?;
+
+    // prepare pda signer seeds for vault
+    let will_key = ctx.accounts.will.key();
+    let vault_seeds = &[
+        VAULT_SEED.as_bytes(),
+        will_key.as_ref(),
+        &[ctx.accounts.will.vault_bump],
+    ];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    // set authority from vault back to testator
+    kind.set_authority(
+        ctx.accounts.target.to_account_info(),
+        ctx.accounts.target_program.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        ctx.accounts.testator.to_account_info(),
+        vault_signer_seeds,
+    )?;
+
+    // update inventory
+    WillInventory::record(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.testator.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.target.key(),
+        AssetKind::Authority,
+        InventoryChange::Withdraw(1),
+    )?;
+
+    Ok(())
+}
@@ -8,13 +8,13 @@ pub struct WithdrawSol<'info> {
     #[account(mut)]
     pub testator: Signer<'info>,
 
-    /// Will account - must be owner by testator and NOT triggered/claimed
+    /// Will account - must be owner by testator and still created/active
     #[account(
         mut,
         constraint = will.testator == testator.key() @ AppError::Unauthorized,
-        constraint = will.status != WillStatus::Triggered @ AppError::CannotWithdrawAfterTriggered,
-        constraint = will.status != WillStatus::Claimed @ AppError::CannotWithdrawAfterTriggered,
         constraint = will.status != WillStatus::Withdrawn @ AppError::AlreadyWithdrawn,
+        constraint = matches!(will.status, WillStatus::Created | WillStatus::Active) @ AppError::CannotWithdrawAfterTriggered,
+        constraint = will.staked_lamports == 0 @ AppError::VaultStakeOutstanding,
     )]
     pub will: Account<'info, Will>,
 
@@ -26,13 +26,13 @@ pub struct WithdrawSol<'info> {
     )]
     pub vault: SystemAccount<'info>,
 
-    /// Config for validation
+    /// Will inventory (grown for new mints)
     #[account(
-        seeds = [CONFIG_SEED.as_bytes()],
-        bump,
-        constraint = !config.paused @ AppError::ProgramPaused,
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
     )]
-    pub config: Account<'info, Config>,
+    pub will_inventory: Account<'info, WillInventory>,
 
     pub system_program: Program<'info, System>,
 }
@@ -50,8 +50,10 @@ impl<'info> WithdrawSol<'info> {
             AppError::InsufficientBalanceForRent
         );
 
-        // validate withdrawable amount
-        let withdrawable_amount = vault_balance.saturating_sub(mint_rent);
+        // validate withdrawable amount (contributions stay for contributor refunds)
+        let withdrawable_amount = vault_balance
+            .saturating_sub(mint_rent)
+            .saturating_sub(self.will.contributed_lamports);
         require!(withdrawable_amount > 0, AppError::NoWithdrawableAmount);
 
         Ok(())
@@ -83,7 +85,9 @@ pub fn handler(ctx: Context<WithdrawSol>) -> Result<()> {
 
     let vault_balance = ctx.accounts.vault.lamports();
     let min_rent = Rent::get()?.minimum_balance(0);
-    let withdrawable_amount = vault_balance.saturating_sub(min_rent);
+    let withdrawable_amount = vault_balance
+        .saturating_sub(min_rent)
+        .saturating_sub(will.contributed_lamports);
 
     transfer(withdraw_transfer_ctx, withdrawable_amount)?;
 
@@ -92,5 +96,13 @@ pub fn handler(ctx: Context<WithdrawSol>) -> Result<()> {
 
     // TODO: Re-create Will: with this seeds mechanism, user cannot re-create new will with same beneficiary after withdraw
 
+    // update inventory
+    WillInventory::record_sol(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.testator.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        InventoryChange::Withdraw(withdrawable_amount),
+    )?;
+
     Ok(())
 }
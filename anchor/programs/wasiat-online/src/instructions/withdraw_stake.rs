@@ -0,0 +1,111 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::{prelude::*, solana_program::stake::state::StakeAuthorize};
+use anchor_spl::stake::{authorize, Authorize, Stake, StakeAccount};
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    /// Testator taking back stake account (pays inventory growth)
+    #[account(mut)]
+    pub testator: Signer<'info>,
+
+    /// Will account - must be owned by testator and NOT triggered/claimed
+    #[account(
+        constraint = will.testator == testator.key() @ AppError::Unauthorized,
+        constraint = matches!(will.status, WillStatus::Created | WillStatus::Active | WillStatus::Withdrawn) @ AppError::CannotWithdrawAfterTriggered,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Native stake account (staker and withdrawer must be vault)
+    #[account(mut)]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// Vault pda (current staker and withdrawer authority)
+    #[account(
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Will inventory (grown for new stake accounts)
+    #[account(
+        mut,
+        seeds = [WILL_INVENTORY_SEED.as_bytes(), will.key().as_ref()],
+        bump = will_inventory.bump,
+    )]
+    pub will_inventory: Account<'info, WillInventory>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub stake_program: Program<'info, Stake>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawStake<'info> {
+    pub fn validate(&self) -> Result<()> {
+        // validate deposited stake account, vault stake settles through withdraw_vault_stake
+        let (vault_stake, _) = Pubkey::find_program_address(
+            &[VAULT_STAKE_SEED.as_bytes(), self.will.key().as_ref()],
+            &crate::ID,
+        );
+        require!(
+            self.stake_account.key() != vault_stake,
+            AppError::InvalidStakeAccount
+        );
+
+        // validate vault holds both authorities
+        let authorized = self
+            .stake_account
+            .authorized()
+            .ok_or(AppError::InvalidStakeAccount)?;
+        let vault = self.vault.key();
+        require!(
+            authorized.staker == vault && authorized.withdrawer == vault,
+            AppError::NoAssetsToWithdraw
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<WithdrawStake>) -> Result<()> {
+    // validations
+    ctx.accounts.validate()?;
+
+    // prepare pda signer seeds for vault
+    let will_key = ctx.accounts.will.key();
+    let vault_seeds = &[
+        VAULT_SEED.as_bytes(),
+        will_key.as_ref(),
+        &[ctx.accounts.will.vault_bump],
+    ];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    // reassign staker and withdrawer authorities from vault back to testator
+    for stake_authorize in [StakeAuthorize::Staker, StakeAuthorize::Withdrawer] {
+        let authorize_accounts = Authorize {
+            stake: ctx.accounts.stake_account.to_account_info(),
+            authorized: ctx.accounts.vault.to_account_info(),
+            new_authorized: ctx.accounts.testator.to_account_info(),
+            clock: ctx.accounts.clock.to_account_info(),
+        };
+
+        let authorize_ctx = CpiContext::new_with_signer(
+            ctx.accounts.stake_program.to_account_info(),
+            authorize_accounts,
+            vault_signer_seeds,
+        );
+
+        authorize(authorize_ctx, stake_authorize, None)?;
+    }
+
+    // update inventory
+    WillInventory::record(
+        &mut ctx.accounts.will_inventory,
+        ctx.accounts.testator.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.stake_account.key(),
+        AssetKind::Stake,
+        InventoryChange::Withdraw(ctx.accounts.stake_account.to_account_info().lamports()),
+    )?;
+
+    Ok(())
+}
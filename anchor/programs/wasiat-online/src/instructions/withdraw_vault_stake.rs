@@ -0,0 +1,74 @@
+use crate::{constants::*, error::AppError, state::*};
+use anchor_lang::{prelude::*, solana_program::sysvar::stake_history};
+use anchor_spl::stake::{withdraw, Stake, StakeAccount, Withdraw};
+
+#[derive(Accounts)]
+pub struct WithdrawVaultStake<'info> {
+    /// Keeper settling a deactivated vault stake (lamports only go back to vault)
+    pub keeper: Signer<'info>,
+
+    /// Will account owning the vault stake
+    #[account(
+        mut,
+        constraint = will.staked_lamports > 0 @ AppError::VaultNotStaked,
+    )]
+    pub will: Account<'info, Will>,
+
+    /// Sol vault pda (destination, withdrawer authority)
+    #[account(
+        mut,
+        seeds = [VAULT_SEED.as_bytes(), will.key().as_ref()],
+        bump = will.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// Vault stake account pda
+    #[account(
+        mut,
+        seeds = [VAULT_STAKE_SEED.as_bytes(), will.key().as_ref()],
+        bump,
+    )]
+    pub vault_stake: Account<'info, StakeAccount>,
+
+    /// CHECK: stake history sysvar
+    #[account(address = stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub stake_program: Program<'info, Stake>,
+}
+
+pub fn handler(ctx: Context<WithdrawVaultStake>) -> Result<()> {
+    // prepare pda signer seeds for vault
+    let will_key = ctx.accounts.will.key();
+    let vault_seeds = &[
+        VAULT_SEED.as_bytes(),
+        will_key.as_ref(),
+        &[ctx.accounts.will.vault_bump],
+    ];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    // withdraw principal and rewards back to vault (fails while stake is still active)
+    let withdraw_accounts = Withdraw {
+        stake: ctx.accounts.vault_stake.to_account_info(),
+        withdrawer: ctx.accounts.vault.to_account_info(),
+        to: ctx.accounts.vault.to_account_info(),
+        clock: ctx.accounts.clock.to_account_info(),
+        stake_history: ctx.accounts.stake_history.to_account_info(),
+    };
+
+    let withdraw_ctx = CpiContext::new_with_signer(
+        ctx.accounts.stake_program.to_account_info(),
+        withdraw_accounts,
+        vault_signer_seeds,
+    );
+
+    let stake_balance = ctx.accounts.vault_stake.to_account_info().lamports();
+    withdraw(withdraw_ctx, stake_balance, None)?;
+
+    // vault sol is liquid again
+    let will = &mut ctx.accounts.will;
+    will.staked_lamports = 0;
+
+    Ok(())
+}
@@ -11,73 +11,356 @@ pub use state::*;
 
 declare_id!("6rs8fcHe8R5xFM56LyaEHGnxjt5QQcrVZWsMbDphQpe4");
 
-#[program]
-pub mod wasiat_online {
+// injected idl instructions (emitted by #[program] beside the program module) still call
+// AccountInfo::realloc, so the program is wrapped to scope the allow to the generated code
+#[allow(deprecated)]
+mod processor {
     use super::*;
 
-    pub fn initialize(
-        ctx: Context<Initialize>,
-        token_fee_bps: u16,
-        nft_fee_lamports: u64,
-        min_heartbeat_period: u32,
-        max_heartbeat_period: u32,
-        min_heartbeat_interval: u32,
-    ) -> Result<()> {
-        initialize::handler(
-            ctx,
-            token_fee_bps,
-            nft_fee_lamports,
-            min_heartbeat_period,
-            max_heartbeat_period,
-            min_heartbeat_interval,
-        )
-    }
+    #[program]
+    pub mod wasiat_online {
+        use super::*;
 
-    pub fn create_will(
-        ctx: Context<CreateWill>,
-        beneficiary: Pubkey,
-        heartbeat_period: u32,
-    ) -> Result<()> {
-        create_will::handler(ctx, beneficiary, heartbeat_period)
-    }
+        pub fn initialize(
+            ctx: Context<Initialize>,
+            token_fee_bps: u16,
+            nft_fee_lamports: u64,
+            min_heartbeat_period: u32,
+            max_heartbeat_period: u32,
+            min_heartbeat_interval: u32,
+        ) -> Result<()> {
+            initialize::handler(
+                ctx,
+                token_fee_bps,
+                nft_fee_lamports,
+                min_heartbeat_period,
+                max_heartbeat_period,
+                min_heartbeat_interval,
+            )
+        }
 
-    pub fn deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
-        deposit_sol::handler(ctx, amount)
-    }
+        pub fn create_will(
+            ctx: Context<CreateWill>,
+            beneficiary: Pubkey,
+            heartbeat_period: u32,
+            referrer: Option<Pubkey>,
+        ) -> Result<()> {
+            create_will::handler(ctx, beneficiary, heartbeat_period, referrer)
+        }
 
-    pub fn send_heartbeat(ctx: Context<SendHeartbeat>) -> Result<()> {
-        send_heartbeat::handler(ctx)
-    }
+        pub fn set_contingent_beneficiary(
+            ctx: Context<SetContingentBeneficiary>,
+            contingent_beneficiary: Option<Pubkey>,
+            claim_deadline: u32,
+        ) -> Result<()> {
+            set_contingent_beneficiary::handler(ctx, contingent_beneficiary, claim_deadline)
+        }
 
-    pub fn trigger_will(ctx: Context<TriggerWill>) -> Result<()> {
-        trigger_will::handler(ctx)
-    }
+        pub fn set_fallback(
+            ctx: Context<SetFallback>,
+            fallback: Option<Pubkey>,
+            unclaimed_expiry: u32,
+        ) -> Result<()> {
+            set_fallback::handler(ctx, fallback, unclaimed_expiry)
+        }
 
-    pub fn claim_sol(ctx: Context<ClaimSol>) -> Result<()> {
-        claim_sol::handler(ctx)
-    }
+        pub fn set_payout_schedule(
+            ctx: Context<SetPayoutSchedule>,
+            payout_schedule: PayoutSchedule,
+        ) -> Result<()> {
+            set_payout_schedule::handler(ctx, payout_schedule)
+        }
 
-    pub fn withdraw_sol(ctx: Context<WithdrawSol>) -> Result<()> {
-        withdraw_sol::handler(ctx)
-    }
+        pub fn set_release_date(
+            ctx: Context<SetReleaseDate>,
+            release_not_before: Option<i64>,
+        ) -> Result<()> {
+            set_release_date::handler(ctx, release_not_before)
+        }
+
+        pub fn subscribe(ctx: Context<Subscribe>) -> Result<()> {
+            subscribe::handler(ctx)
+        }
+
+        pub fn adopt_current_fees(ctx: Context<AdoptCurrentFees>) -> Result<()> {
+            adopt_current_fees::handler(ctx)
+        }
+
+        pub fn set_executor(
+            ctx: Context<SetExecutor>,
+            executor: Option<Pubkey>,
+            executor_fee_bps: u16,
+        ) -> Result<()> {
+            set_executor::handler(ctx, executor, executor_fee_bps)
+        }
+
+        pub fn migrate_will(ctx: Context<MigrateWill>) -> Result<()> {
+            migrate_will::handler(ctx)
+        }
+
+        pub fn deposit_sol(
+            ctx: Context<DepositSol>,
+            amount: u64,
+            as_heartbeat: bool,
+        ) -> Result<()> {
+            deposit_sol::handler(ctx, amount, as_heartbeat)
+        }
+
+        pub fn deposit_spl_token(
+            ctx: Context<DepositSplToken>,
+            amount: u64,
+            as_heartbeat: bool,
+        ) -> Result<()> {
+            deposit_spl_token::handler(ctx, amount, as_heartbeat)
+        }
+
+        pub fn deposit_nft(ctx: Context<DepositNft>, as_heartbeat: bool) -> Result<()> {
+            deposit_nft::handler(ctx, as_heartbeat)
+        }
+
+        pub fn deposit_batch<'info>(
+            ctx: Context<'_, '_, 'info, 'info, DepositBatch<'info>>,
+            items: Vec<DepositItem>,
+            as_heartbeat: bool,
+        ) -> Result<()> {
+            deposit_batch::handler(ctx, items, as_heartbeat)
+        }
+
+        pub fn contribute_sol(ctx: Context<ContributeSol>, amount: u64) -> Result<()> {
+            contribute_sol::handler(ctx, amount)
+        }
+
+        pub fn contribute_spl_token(ctx: Context<ContributeSplToken>, amount: u64) -> Result<()> {
+            contribute_spl_token::handler(ctx, amount)
+        }
+
+        pub fn refund_contribution(ctx: Context<RefundContribution>) -> Result<()> {
+            refund_contribution::handler(ctx)
+        }
+
+        pub fn refund_spl_contribution(ctx: Context<RefundSplContribution>) -> Result<()> {
+            refund_spl_contribution::handler(ctx)
+        }
+
+        pub fn deposit_stake(ctx: Context<DepositStake>, as_heartbeat: bool) -> Result<()> {
+            deposit_stake::handler(ctx, as_heartbeat)
+        }
+
+        pub fn deposit_authority(
+            ctx: Context<DepositAuthority>,
+            kind: AuthorityKind,
+            as_heartbeat: bool,
+        ) -> Result<()> {
+            deposit_authority::handler(ctx, kind, as_heartbeat)
+        }
+
+        pub fn stake_vault_sol(ctx: Context<StakeVaultSol>, amount: u64) -> Result<()> {
+            stake_vault_sol::handler(ctx, amount)
+        }
+
+        pub fn deactivate_vault_stake(ctx: Context<DeactivateVaultStake>) -> Result<()> {
+            deactivate_vault_stake::handler(ctx)
+        }
+
+        pub fn withdraw_vault_stake(ctx: Context<WithdrawVaultStake>) -> Result<()> {
+            withdraw_vault_stake::handler(ctx)
+        }
+
+        pub fn add_will_action(
+            ctx: Context<AddWillAction>,
+            program_id: Pubkey,
+            accounts: Vec<ActionAccountMeta>,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            add_will_action::handler(ctx, program_id, accounts, data)
+        }
+
+        pub fn remove_will_action(ctx: Context<RemoveWillAction>) -> Result<()> {
+            remove_will_action::handler(ctx)
+        }
+
+        pub fn send_heartbeat(ctx: Context<SendHeartbeat>) -> Result<()> {
+            send_heartbeat::handler(ctx)
+        }
+
+        pub fn trigger_will(ctx: Context<TriggerWill>) -> Result<()> {
+            trigger_will::handler(ctx)
+        }
+
+        pub fn execute_will_action<'info>(
+            ctx: Context<'_, '_, 'info, 'info, ExecuteWillAction<'info>>,
+        ) -> Result<()> {
+            execute_will_action::handler(ctx)
+        }
+
+        pub fn claim_sol(ctx: Context<ClaimSol>) -> Result<()> {
+            claim_sol::handler(ctx)
+        }
 
-    pub fn update_config(
-        ctx: Context<UpdateConfig>,
-        token_fee_bps: Option<u16>,
-        nft_fee_lamports: Option<u64>,
-        min_heartbeat_period: Option<u32>,
-        max_heartbeat_period: Option<u32>,
-        min_heartbeat_interval: Option<u32>,
-        paused: Option<bool>,
-    ) -> Result<()> {
-        update_config::handler(
-            ctx,
-            token_fee_bps,
-            nft_fee_lamports,
-            min_heartbeat_period,
-            max_heartbeat_period,
-            min_heartbeat_interval,
-            paused,
-        )
+        pub fn claim_spl_token(ctx: Context<ClaimSplToken>) -> Result<()> {
+            claim_spl_token::handler(ctx)
+        }
+
+        pub fn claim_nft(ctx: Context<ClaimNft>) -> Result<()> {
+            claim_nft::handler(ctx)
+        }
+
+        pub fn claim_stake(ctx: Context<ClaimStake>) -> Result<()> {
+            claim_stake::handler(ctx)
+        }
+
+        pub fn claim_authority(ctx: Context<ClaimAuthority>, kind: AuthorityKind) -> Result<()> {
+            claim_authority::handler(ctx, kind)
+        }
+
+        pub fn claim_all<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimAll<'info>>) -> Result<()> {
+            claim_all::handler(ctx)
+        }
+
+        pub fn distribute_sol(ctx: Context<DistributeSol>) -> Result<()> {
+            distribute_sol::handler(ctx)
+        }
+
+        pub fn distribute_spl_token(ctx: Context<DistributeSplToken>) -> Result<()> {
+            distribute_spl_token::handler(ctx)
+        }
+
+        pub fn distribute_nft(ctx: Context<DistributeNft>) -> Result<()> {
+            distribute_nft::handler(ctx)
+        }
+
+        pub fn reclaim_unclaimed_sol(ctx: Context<ReclaimUnclaimedSol>) -> Result<()> {
+            reclaim_unclaimed_sol::handler(ctx)
+        }
+
+        pub fn reclaim_unclaimed_spl_token(ctx: Context<ReclaimUnclaimedSplToken>) -> Result<()> {
+            reclaim_unclaimed_spl_token::handler(ctx)
+        }
+
+        pub fn withdraw_sol(ctx: Context<WithdrawSol>) -> Result<()> {
+            withdraw_sol::handler(ctx)
+        }
+
+        pub fn withdraw_authority(
+            ctx: Context<WithdrawAuthority>,
+            kind: AuthorityKind,
+        ) -> Result<()> {
+            withdraw_authority::handler(ctx, kind)
+        }
+
+        pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+            withdraw_stake::handler(ctx)
+        }
+
+        pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+            migrate_config::handler(ctx)
+        }
+
+        pub fn update_config(ctx: Context<UpdateConfig>, pause_flags: u8) -> Result<()> {
+            update_config::handler(ctx, pause_flags)
+        }
+
+        pub fn queue_config_change(
+            ctx: Context<QueueConfigChange>,
+            change: ConfigChange,
+        ) -> Result<()> {
+            queue_config_change::handler(ctx, change)
+        }
+
+        pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+            execute_config_change::handler(ctx)
+        }
+
+        pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+            cancel_config_change::handler(ctx)
+        }
+
+        pub fn queue_fee_schedule(
+            ctx: Context<QueueFeeSchedule>,
+            sol_tiers: Vec<FeeTier>,
+            sol_min_fee: u64,
+            sol_max_fee: u64,
+            mint_fees: Vec<MintFee>,
+            annual_fee_lamports: u64,
+            referrer_share_bps: u16,
+        ) -> Result<()> {
+            queue_fee_schedule::handler(
+                ctx,
+                sol_tiers,
+                sol_min_fee,
+                sol_max_fee,
+                mint_fees,
+                annual_fee_lamports,
+                referrer_share_bps,
+            )
+        }
+
+        pub fn execute_fee_schedule(ctx: Context<ExecuteFeeSchedule>) -> Result<()> {
+            execute_fee_schedule::handler(ctx)
+        }
+
+        pub fn cancel_fee_schedule(ctx: Context<CancelFeeSchedule>) -> Result<()> {
+            cancel_fee_schedule::handler(ctx)
+        }
+
+        pub fn set_admin_role(
+            ctx: Context<SetAdminRole>,
+            role: AdminRole,
+            holder: Option<Pubkey>,
+        ) -> Result<()> {
+            set_admin_role::handler(ctx, role, holder)
+        }
+
+        pub fn upgrade_program(ctx: Context<UpgradeProgram>) -> Result<()> {
+            upgrade_program::handler(ctx)
+        }
+
+        pub fn set_config_authority(ctx: Context<SetConfigAuthority>) -> Result<()> {
+            set_config_authority::handler(ctx)
+        }
+
+        pub fn create_admin_set(
+            ctx: Context<CreateAdminSet>,
+            members: Vec<Pubkey>,
+            threshold: u8,
+        ) -> Result<()> {
+            create_admin_set::handler(ctx, members, threshold)
+        }
+
+        pub fn update_admin_set(
+            ctx: Context<UpdateAdminSet>,
+            members: Vec<Pubkey>,
+            threshold: u8,
+        ) -> Result<()> {
+            update_admin_set::handler(ctx, members, threshold)
+        }
+
+        pub fn propose_admin_action(
+            ctx: Context<ProposeAdminAction>,
+            accounts: Vec<ActionAccountMeta>,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            propose_admin_action::handler(ctx, accounts, data)
+        }
+
+        pub fn approve_admin_action(ctx: Context<ApproveAdminAction>) -> Result<()> {
+            approve_admin_action::handler(ctx)
+        }
+
+        pub fn execute_admin_action<'info>(
+            ctx: Context<'_, '_, 'info, 'info, ExecuteAdminAction<'info>>,
+        ) -> Result<()> {
+            execute_admin_action::handler(ctx)
+        }
+
+        pub fn update_allowlist(
+            ctx: Context<UpdateAllowlist>,
+            program_id: Pubkey,
+            allowed: bool,
+        ) -> Result<()> {
+            update_allowlist::handler(ctx, program_id, allowed)
+        }
     }
 }
+
+pub use processor::*;
@@ -0,0 +1,28 @@
+use crate::{constants::*, state::*};
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct AdminProposal {
+    pub admin_set: Pubkey, // admin set voting on this proposal
+    pub index: u64,        // pda index within admin set
+    pub proposer: Pubkey,  // member that proposed (receives rent back)
+    #[max_len(MAX_ACTION_ACCOUNTS)]
+    pub accounts: Vec<ActionAccountMeta>, // account metas, other signers than admin signer co-sign execution
+    #[max_len(MAX_ADMIN_ACTION_DATA_LEN)]
+    pub data: Vec<u8>, // instruction data for this program
+    #[max_len(MAX_ADMIN_MEMBERS)]
+    pub approvals: Vec<Pubkey>, // members that approved
+    pub created_at: i64, // timestamp
+    pub bump: u8,        // pda bump
+}
+
+impl AdminProposal {
+    /// Approvals from current members only (removed members no longer count)
+    pub fn approval_count(&self, admin_set: &AdminSet) -> usize {
+        self.approvals
+            .iter()
+            .filter(|approver| admin_set.is_member(approver))
+            .count()
+    }
+}
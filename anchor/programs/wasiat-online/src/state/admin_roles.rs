@@ -0,0 +1,34 @@
+use crate::state::Config;
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct AdminRoles {
+    pub pauser: Option<Pubkey>,      // pauses/unpauses (config owner if unset)
+    pub fee_manager: Option<Pubkey>, // changes fees (config owner if unset)
+    pub upgrader: Option<Pubkey>,    // program upgrades (config owner if unset)
+    pub bump: u8,                    // pda bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AdminRole {
+    Pauser,     // 0 - pause flags
+    FeeManager, // 1 - fee changes and fee schedule
+    Upgrader,   // 2 - program upgrades
+}
+
+impl AdminRoles {
+    /// Key holding `role`, unassigned roles stay with the config owner
+    pub fn holder(&self, role: AdminRole, config: &Config) -> Pubkey {
+        match role {
+            AdminRole::Pauser => self.pauser,
+            AdminRole::FeeManager => self.fee_manager,
+            AdminRole::Upgrader => self.upgrader,
+        }
+        .unwrap_or(config.authority)
+    }
+
+    pub fn has_role(&self, role: AdminRole, config: &Config, key: &Pubkey) -> bool {
+        self.holder(role, config) == *key
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{constants::*, error::AppError};
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct AdminSet {
+    #[max_len(MAX_ADMIN_MEMBERS)]
+    pub members: Vec<Pubkey>, // admins that can propose and approve
+    pub threshold: u8,       // approvals needed to execute a proposal
+    pub proposal_count: u64, // pda index for next proposal
+    pub bump: u8,            // pda bump
+    pub signer_bump: u8,     // admin signer pda bump (config authority)
+}
+
+impl AdminSet {
+    pub fn validate_members(members: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !members.is_empty() && members.len() <= MAX_ADMIN_MEMBERS,
+            AppError::InvalidAdminSet
+        );
+        require!(
+            threshold > 0 && threshold as usize <= members.len(),
+            AppError::InvalidAdminSet
+        );
+        for (i, member) in members.iter().enumerate() {
+            require!(!members[..i].contains(member), AppError::InvalidAdminSet);
+        }
+
+        Ok(())
+    }
+
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+}
@@ -0,0 +1,254 @@
+use crate::error::AppError;
+use anchor_lang::prelude::*;
+#[allow(deprecated)]
+use anchor_lang::solana_program::{
+    bpf_loader_upgradeable::ID as BPF_LOADER_UPGRADEABLE_ID,
+    instruction::{AccountMeta, Instruction},
+    loader_upgradeable_instruction::UpgradeableLoaderInstruction,
+    program::invoke_signed,
+};
+use anchor_spl::{
+    token::ID as TOKEN_PROGRAM_ID,
+    token_2022::ID as TOKEN_2022_PROGRAM_ID,
+    token_interface::{
+        set_authority, spl_token_2022::instruction::AuthorityType, Mint, SetAuthority, TokenAccount,
+    },
+};
+
+/// Squads v4 multisig program
+pub const SQUADS_PROGRAM_ID: Pubkey = pubkey!("SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf");
+/// Squads v4 `Multisig` account discriminator
+const SQUADS_MULTISIG_DISCRIMINATOR: [u8; 8] = [224, 116, 121, 186, 68, 161, 79, 236];
+/// Squads v4 `multisig_set_config_authority` instruction discriminator
+const SQUADS_SET_CONFIG_AUTHORITY_DISCRIMINATOR: [u8; 8] = [143, 93, 199, 143, 92, 169, 193, 232];
+/// Offset of `config_authority` in a squads multisig (after discriminator and create key)
+const SQUADS_CONFIG_AUTHORITY_OFFSET: usize = 8 + 32;
+
+/// Authority that can be handed over to the vault and later to the beneficiary
+///
+/// Multisig memberships are inherited through the multisig's config authority, which
+/// adds and removes members without a vote. Authorities of other programs can be
+/// handed over on trigger with a will action calling the program's set authority.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AuthorityKind {
+    ProgramUpgrade, // 0 - upgrade authority of a bpf upgradeable program (target: program data)
+    MintTokens,     // 1 - spl mint authority (target: mint)
+    FreezeAccount,  // 2 - spl freeze authority (target: mint)
+    AccountOwner,   // 3 - spl token account owner (target: token account)
+    CloseAccount,   // 4 - spl token account close authority (target: token account)
+    MultisigConfig, // 5 - squads v4 config authority, manages members (target: multisig)
+}
+
+impl AuthorityKind {
+    /// Reads the current authority of `target`, validating it against the kind
+    pub fn current_authority(
+        &self,
+        target: &AccountInfo,
+        target_program: &AccountInfo,
+    ) -> Result<Option<Pubkey>> {
+        require_keys_eq!(
+            *target.owner,
+            target_program.key(),
+            AppError::InvalidAuthorityTarget
+        );
+
+        match self {
+            AuthorityKind::ProgramUpgrade => {
+                require_keys_eq!(
+                    target_program.key(),
+                    BPF_LOADER_UPGRADEABLE_ID,
+                    AppError::InvalidAuthorityTarget
+                );
+                let program_data = ProgramData::try_deserialize(&mut &target.data.borrow()[..])?;
+                Ok(program_data.upgrade_authority_address)
+            }
+            AuthorityKind::MintTokens | AuthorityKind::FreezeAccount => {
+                require!(
+                    target_program.key() == TOKEN_PROGRAM_ID
+                        || target_program.key() == TOKEN_2022_PROGRAM_ID,
+                    AppError::InvalidAuthorityTarget
+                );
+                let mint = Mint::try_deserialize(&mut &target.data.borrow()[..])?;
+                let authority = if *self == AuthorityKind::MintTokens {
+                    mint.mint_authority
+                } else {
+                    mint.freeze_authority
+                };
+                Ok(authority.into())
+            }
+            AuthorityKind::AccountOwner | AuthorityKind::CloseAccount => {
+                require!(
+                    target_program.key() == TOKEN_PROGRAM_ID
+                        || target_program.key() == TOKEN_2022_PROGRAM_ID,
+                    AppError::InvalidAuthorityTarget
+                );
+                let token_account = TokenAccount::try_deserialize(&mut &target.data.borrow()[..])?;
+                if *self == AuthorityKind::AccountOwner {
+                    Ok(Some(token_account.owner))
+                } else {
+                    Ok(token_account.close_authority.into())
+                }
+            }
+            AuthorityKind::MultisigConfig => {
+                require_keys_eq!(
+                    target_program.key(),
+                    SQUADS_PROGRAM_ID,
+                    AppError::InvalidAuthorityTarget
+                );
+                let data = target.data.borrow();
+                require!(
+                    data.len() >= SQUADS_CONFIG_AUTHORITY_OFFSET + 32
+                        && data.starts_with(&SQUADS_MULTISIG_DISCRIMINATOR),
+                    AppError::InvalidAuthorityTarget
+                );
+
+                // default key marks an autonomous multisig (members vote on changes)
+                let config_authority = Pubkey::try_from(
+                    &data[SQUADS_CONFIG_AUTHORITY_OFFSET..SQUADS_CONFIG_AUTHORITY_OFFSET + 32],
+                )
+                .map_err(|_| AppError::InvalidAuthorityTarget)?;
+                Ok((config_authority != Pubkey::default()).then_some(config_authority))
+            }
+        }
+    }
+
+    /// Moves the authority of `target` from `current` to `new`
+    pub fn set_authority<'info>(
+        &self,
+        target: AccountInfo<'info>,
+        target_program: AccountInfo<'info>,
+        current: AccountInfo<'info>,
+        new: AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        match self {
+            AuthorityKind::ProgramUpgrade => {
+                let set_authority_ix = Instruction::new_with_bincode(
+                    BPF_LOADER_UPGRADEABLE_ID,
+                    &UpgradeableLoaderInstruction::SetAuthority,
+                    vec![
+                        AccountMeta::new(target.key(), false),
+                        AccountMeta::new_readonly(current.key(), true),
+                        AccountMeta::new_readonly(new.key(), false),
+                    ],
+                );
+
+                invoke_signed(
+                    &set_authority_ix,
+                    &[target, current, new, target_program],
+                    signer_seeds,
+                )?;
+            }
+            AuthorityKind::MultisigConfig => {
+                // set config authority (no memo), rent payer and system program left empty
+                let mut data = SQUADS_SET_CONFIG_AUTHORITY_DISCRIMINATOR.to_vec();
+                data.extend_from_slice(new.key().as_ref());
+                data.push(0);
+
+                let set_config_authority_ix = Instruction {
+                    program_id: SQUADS_PROGRAM_ID,
+                    accounts: vec![
+                        AccountMeta::new(target.key(), false),
+                        AccountMeta::new_readonly(current.key(), true),
+                        AccountMeta::new_readonly(SQUADS_PROGRAM_ID, false),
+                        AccountMeta::new_readonly(SQUADS_PROGRAM_ID, false),
+                    ],
+                    data,
+                };
+
+                invoke_signed(
+                    &set_config_authority_ix,
+                    &[target, current, target_program],
+                    signer_seeds,
+                )?;
+            }
+            AuthorityKind::MintTokens
+            | AuthorityKind::FreezeAccount
+            | AuthorityKind::AccountOwner
+            | AuthorityKind::CloseAccount => {
+                let authority_type = match self {
+                    AuthorityKind::MintTokens => AuthorityType::MintTokens,
+                    AuthorityKind::FreezeAccount => AuthorityType::FreezeAccount,
+                    AuthorityKind::AccountOwner => AuthorityType::AccountOwner,
+                    _ => AuthorityType::CloseAccount,
+                };
+
+                let set_authority_accounts = SetAuthority {
+                    current_authority: current,
+                    account_or_mint: target,
+                };
+
+                let set_authority_ctx = CpiContext::new_with_signer(
+                    target_program,
+                    set_authority_accounts,
+                    signer_seeds,
+                );
+
+                set_authority(set_authority_ctx, authority_type, Some(new.key()))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn multisig_data(config_authority: Pubkey) -> Vec<u8> {
+        let mut data = SQUADS_MULTISIG_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(Pubkey::new_unique().as_ref()); // create key
+        data.extend_from_slice(config_authority.as_ref());
+        data.extend_from_slice(&[1, 0]); // threshold
+        data
+    }
+
+    fn multisig_config_authority(data: &mut [u8]) -> Result<Option<Pubkey>> {
+        let key = Pubkey::new_unique();
+        let (mut lamports, mut program_lamports) = (0, 0);
+        let target = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            data,
+            &SQUADS_PROGRAM_ID,
+            false,
+            0,
+        );
+        let program_owner = Pubkey::default();
+        let target_program = AccountInfo::new(
+            &SQUADS_PROGRAM_ID,
+            false,
+            false,
+            &mut program_lamports,
+            &mut [],
+            &program_owner,
+            true,
+            0,
+        );
+        AuthorityKind::MultisigConfig.current_authority(&target, &target_program)
+    }
+
+    #[test]
+    fn multisig_config_authority_is_read_from_controlled_multisigs() {
+        let config_authority = Pubkey::new_unique();
+        let mut data = multisig_data(config_authority);
+        assert_eq!(
+            multisig_config_authority(&mut data).unwrap(),
+            Some(config_authority)
+        );
+
+        // autonomous multisigs have no config authority to hand over
+        let mut data = multisig_data(Pubkey::default());
+        assert_eq!(multisig_config_authority(&mut data).unwrap(), None);
+    }
+
+    #[test]
+    fn multisig_config_authority_rejects_other_accounts() {
+        let mut data = multisig_data(Pubkey::new_unique());
+        data[0] ^= 1;
+        assert!(multisig_config_authority(&mut data).is_err());
+    }
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimRecord {
+    pub will: Pubkey,        // will being claimed
+    pub mint: Pubkey,        // token mint
+    pub claimed_so_far: u64, // tokens released from vault (incl. fees)
+    pub vesting_total: u64,  // tokens vesting under payout schedule (snapshot at first release)
+    pub bump: u8,            // pda bump
+}
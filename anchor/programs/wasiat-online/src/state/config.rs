@@ -1,3 +1,4 @@
+use crate::{constants::*, error::AppError, state::FeeSnapshot};
 use anchor_lang::prelude::*;
 
 #[account]
@@ -10,9 +11,154 @@ pub struct Config {
     pub min_heartbeat_period: u32,   // seconds - minimum will duration
     pub max_heartbeat_period: u32,   // seconds - maximum will duration
     pub min_heartbeat_interval: u32, // seconds - cooldown between heartbeats
-    pub paused: bool,                // emergency pause
+    pub pause_flags: u8,             // paused operations bitmask (PAUSE_* constants)
     pub bump: u8,                    // pda bump
-    pub reserved: [u8; 28],          // reserved space (reduced from 32 to 28)
+    pub paused_since: i64,           // timestamp - trigger pause started (0 if trigger not paused)
+    pub total_paused_seconds: u64,   // seconds - finished trigger pauses, excluded from will expiry
+    pub last_pause_ended_at: i64,    // timestamp - last trigger pause lifted (0 if never)
+    pub pause_flags_migrated: bool,  // legacy paused bool mapped into pause_flags
+    pub reserved: [u8; 3],           // reserved space (reduced from 4 to 3)
 }
 
-impl Config {}
+impl Config {
+    /// Fees snapshotted on wills created now
+    pub fn current_fees(&self) -> FeeSnapshot {
+        FeeSnapshot {
+            token_fee_bps: self.token_fee_bps,
+            nft_fee_lamports: self.nft_fee_lamports,
+        }
+    }
+
+    /// Whether any operation in `flags` is paused
+    pub fn is_paused(&self, flags: u8) -> bool {
+        self.pause_flags & flags != 0
+    }
+
+    /// Seconds trigger has been paused in total, including an ongoing pause
+    pub fn paused_seconds(&self, current_time: i64) -> u64 {
+        let ongoing = if self.paused_since > 0 {
+            current_time.saturating_sub(self.paused_since) as u64
+        } else {
+            0
+        };
+        self.total_paused_seconds + ongoing
+    }
+
+    /// Maps the legacy `paused` bool (read back as pause_flags) onto every pausable operation
+    pub fn migrate_pause_flags(&mut self, current_time: i64) {
+        if self.pause_flags_migrated {
+            return;
+        }
+
+        // legacy pause blocked everything, keep it that way until unpaused
+        if self.pause_flags != 0 {
+            self.pause_flags = PAUSABLE_FLAGS;
+            self.paused_since = current_time;
+        }
+        self.pause_flags_migrated = true;
+    }
+
+    /// Sets paused operations, accounting trigger pause time
+    pub fn set_pause_flags(&mut self, pause_flags: u8, current_time: i64) -> Result<()> {
+        // validate known flags only
+        require!(
+            pause_flags & !PAUSE_FLAGS_MASK == 0,
+            AppError::InvalidPauseFlags
+        );
+
+        // validate testators can always heartbeat and withdraw
+        require!(
+            pause_flags & UNPAUSABLE_FLAGS == 0,
+            AppError::OperationNotPausable
+        );
+
+        // account trigger pause time
+        let trigger_was_paused = self.is_paused(PAUSE_TRIGGER);
+        let trigger_paused = pause_flags & PAUSE_TRIGGER != 0;
+        if !trigger_was_paused && trigger_paused {
+            self.paused_since = current_time;
+        } else if trigger_was_paused && !trigger_paused {
+            self.total_paused_seconds = self.paused_seconds(current_time);
+            self.paused_since = 0;
+            self.last_pause_ended_at = current_time;
+        }
+
+        self.pause_flags = pause_flags;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::fixtures::*;
+
+    #[test]
+    fn paused_seconds_counts_finished_and_ongoing_trigger_pauses() {
+        let mut config = config();
+        assert_eq!(config.paused_seconds(START), 0);
+
+        // first pause lasts 100 seconds
+        config.set_pause_flags(PAUSE_TRIGGER, START).unwrap();
+        assert_eq!(config.paused_seconds(START + 40), 40);
+        config.set_pause_flags(0, START + 100).unwrap();
+        assert_eq!(config.paused_seconds(START + 500), 100);
+        assert_eq!(config.last_pause_ended_at, START + 100);
+
+        // ongoing second pause adds up
+        config
+            .set_pause_flags(PAUSE_TRIGGER, START + 1_000)
+            .unwrap();
+        assert_eq!(config.paused_seconds(START + 1_050), 150);
+    }
+
+    #[test]
+    fn pausing_other_operations_does_not_stop_will_expiry() {
+        let mut config = config();
+        config
+            .set_pause_flags(PAUSE_CREATE | PAUSE_CLAIM, START)
+            .unwrap();
+        assert_eq!(config.paused_seconds(START + 100), 0);
+        assert_eq!(config.paused_since, 0);
+    }
+
+    #[test]
+    fn heartbeat_and_withdraw_are_never_paused() {
+        let mut config = config();
+        assert!(config.set_pause_flags(PAUSE_HEARTBEAT, START).is_err());
+        assert!(config.set_pause_flags(PAUSE_WITHDRAW, START).is_err());
+        assert!(config.set_pause_flags(1 << 7, START).is_err());
+        assert_eq!(config.pause_flags, 0);
+    }
+
+    #[test]
+    fn legacy_pause_maps_to_every_pausable_operation() {
+        // legacy `paused = true` reads back as PAUSE_CREATE
+        let mut config = config();
+        config.pause_flags = 1;
+        config.pause_flags_migrated = false;
+
+        config.migrate_pause_flags(START);
+        assert_eq!(config.pause_flags, PAUSABLE_FLAGS);
+        assert!(config.is_paused(PAUSE_DEPOSIT | PAUSE_TRIGGER | PAUSE_CLAIM));
+        assert!(!config.is_paused(UNPAUSABLE_FLAGS));
+        assert_eq!(config.paused_since, START);
+
+        // migrated configs are left alone
+        config.set_pause_flags(PAUSE_CREATE, START + 10).unwrap();
+        config.migrate_pause_flags(START + 20);
+        assert_eq!(config.pause_flags, PAUSE_CREATE);
+    }
+
+    #[test]
+    fn unpaused_legacy_config_stays_unpaused() {
+        let mut config = config();
+        config.pause_flags_migrated = false;
+
+        config.migrate_pause_flags(START);
+        assert_eq!(config.pause_flags, 0);
+        assert_eq!(config.paused_since, 0);
+        assert!(config.pause_flags_migrated);
+    }
+}
@@ -0,0 +1,13 @@
+use crate::state::AssetKind;
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Contribution {
+    pub will: Pubkey,        // will receiving contributions
+    pub contributor: Pubkey, // third party contributing (no rights over will)
+    pub mint: Pubkey,        // token mint (native mint for SOL)
+    pub kind: AssetKind,     // inventory asset kind, fixed at first contribution
+    pub amount: u64,         // total contributed
+    pub bump: u8,            // pda bump
+}
@@ -0,0 +1,231 @@
+use crate::{constants::*, error::AppError};
+use anchor_lang::prelude::*;
+
+/// Marginal SOL fee bracket, applied to the part of an amount above `above`
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy)]
+pub struct FeeTier {
+    pub above: u64,   // lamports - bracket start
+    pub fee_bps: u16, // basis points for lamports in this bracket
+}
+
+/// SPL token fee override for one mint
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy)]
+pub struct MintFee {
+    pub mint: Pubkey, // token mint
+    pub fee_bps: u16, // basis points
+    pub min_fee: u64, // token base units - minimum fee
+    pub max_fee: u64, // token base units - maximum fee (0 if uncapped)
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct FeeSchedule {
+    #[max_len(MAX_FEE_TIERS)]
+    pub sol_tiers: Vec<FeeTier>, // ascending brackets, first starts at 0 (empty for flat fee)
+    pub sol_min_fee: u64, // lamports - minimum SOL fee
+    pub sol_max_fee: u64, // lamports - maximum SOL fee (0 if uncapped)
+    #[max_len(MAX_MINT_FEE_OVERRIDES)]
+    pub mint_fees: Vec<MintFee>, // per-mint overrides (other mints pay flat fee)
+    pub annual_fee_lamports: u64, // subscription fee per year instead of claim bps (0 if not offered)
+    pub referrer_share_bps: u16,  // share of service fees paid to will referrer
+    pub effective_at: i64, // timestamp - schedule took effect (0 for the initial flat schedule)
+    pub bump: u8,          // pda bump
+}
+
+impl FeeSchedule {
+    pub fn validate(&self) -> Result<()> {
+        // validate fits allocated space
+        require!(
+            self.sol_tiers.len() <= MAX_FEE_TIERS && self.mint_fees.len() <= MAX_MINT_FEE_OVERRIDES,
+            AppError::InvalidFeeSchedule
+        );
+
+        // validate sol brackets start at 0 and ascend
+        if let Some(first) = self.sol_tiers.first() {
+            require!(first.above == 0, AppError::InvalidFeeSchedule);
+        }
+        require!(
+            self.sol_tiers.windows(2).all(|w| w[0].above < w[1].above),
+            AppError::InvalidFeeSchedule
+        );
+        require!(
+            self.sol_tiers
+                .iter()
+                .all(|tier| tier.fee_bps <= MAX_TOKEN_FEE_BPS),
+            AppError::InvalidTokenFee
+        );
+        require!(
+            valid_bounds(self.sol_min_fee, self.sol_max_fee),
+            AppError::InvalidFeeSchedule
+        );
+
+        // validate subscription fee cap
+        require!(
+            self.annual_fee_lamports <= MAX_ANNUAL_FEE_LAMPORTS,
+            AppError::InvalidFeeSchedule
+        );
+
+        // validate referrer share cap
+        require!(
+            self.referrer_share_bps <= MAX_REFERRER_SHARE_BPS,
+            AppError::InvalidFeeSchedule
+        );
+
+        // validate mint overrides unique and bounded
+        for (i, mint_fee) in self.mint_fees.iter().enumerate() {
+            require!(
+                self.mint_fees[..i].iter().all(|m| m.mint != mint_fee.mint),
+                AppError::InvalidFeeSchedule
+            );
+            require!(
+                mint_fee.fee_bps <= MAX_TOKEN_FEE_BPS,
+                AppError::InvalidTokenFee
+            );
+            require!(
+                valid_bounds(mint_fee.min_fee, mint_fee.max_fee),
+                AppError::InvalidFeeSchedule
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Fee on `amount` lamports: tiered (the will's `flat_fee` without tiers), within min/max
+    pub fn sol_fee(&self, amount: u64, flat_fee: u64) -> u64 {
+        if self.sol_tiers.is_empty() {
+            return bound_fee(flat_fee, self.sol_min_fee, self.sol_max_fee, amount);
+        }
+
+        let mut fee: u128 = 0;
+        for (i, tier) in self.sol_tiers.iter().enumerate() {
+            if amount <= tier.above {
+                break;
+            }
+            let bracket_end = self
+                .sol_tiers
+                .get(i + 1)
+                .map_or(amount, |next| next.above.min(amount));
+            fee += (bracket_end - tier.above) as u128 * tier.fee_bps as u128 / 10_000;
+        }
+
+        bound_fee(fee as u64, self.sol_min_fee, self.sol_max_fee, amount)
+    }
+
+    /// Fee on `amount` of `mint`: per-mint override within its min/max, else the will's `flat_fee`
+    pub fn token_fee(&self, mint: &Pubkey, amount: u64, flat_fee: u64) -> u64 {
+        match self.mint_fees.iter().find(|m| m.mint == *mint) {
+            Some(mint_fee) => {
+                let fee = (amount as u128 * mint_fee.fee_bps as u128 / 10_000) as u64;
+                bound_fee(fee, mint_fee.min_fee, mint_fee.max_fee, amount)
+            }
+            None => flat_fee,
+        }
+    }
+
+    /// Limits `scheduled_fee` to the will's snapshotted `flat_fee` for wills created
+    /// before this schedule took effect, so a schedule never raises their fees
+    pub fn applied_fee(&self, will_created_at: i64, scheduled_fee: u64, flat_fee: u64) -> u64 {
+        if will_created_at < self.effective_at {
+            scheduled_fee.min(flat_fee)
+        } else {
+            scheduled_fee
+        }
+    }
+}
+
+fn valid_bounds(min_fee: u64, max_fee: u64) -> bool {
+    max_fee == 0 || min_fee <= max_fee
+}
+
+/// Clamps `fee` into min/max (max 0 if uncapped), never above `MAX_TOKEN_FEE_BPS` of `amount`
+fn bound_fee(fee: u64, min_fee: u64, max_fee: u64, amount: u64) -> u64 {
+    let fee = fee.max(min_fee);
+    let fee = if max_fee > 0 { fee.min(max_fee) } else { fee };
+    let fee_cap = (amount as u128 * MAX_TOKEN_FEE_BPS as u128 / 10_000) as u64;
+    fee.min(fee_cap)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::fixtures::*;
+
+    #[test]
+    fn sol_fee_without_tiers_is_flat_fee() {
+        let fee_schedule = flat_fee_schedule();
+        assert_eq!(fee_schedule.sol_fee(1_000_000, 25_000), 25_000);
+    }
+
+    #[test]
+    fn sol_fee_applies_tiers_marginally() {
+        let mut fee_schedule = flat_fee_schedule();
+        fee_schedule.sol_tiers = vec![
+            FeeTier {
+                above: 0,
+                fee_bps: 500,
+            },
+            FeeTier {
+                above: 1_000_000,
+                fee_bps: 100,
+            },
+        ];
+
+        // 5% of the first 1_000_000, 1% of the rest
+        assert_eq!(fee_schedule.sol_fee(500_000, 0), 25_000);
+        assert_eq!(fee_schedule.sol_fee(1_000_000, 0), 50_000);
+        assert_eq!(fee_schedule.sol_fee(3_000_000, 0), 70_000);
+    }
+
+    #[test]
+    fn sol_fee_within_min_and_max() {
+        let mut fee_schedule = flat_fee_schedule();
+        fee_schedule.sol_min_fee = 10_000;
+        fee_schedule.sol_max_fee = 40_000;
+
+        assert_eq!(fee_schedule.sol_fee(1_000_000, 5_000), 10_000);
+        assert_eq!(fee_schedule.sol_fee(1_000_000, 25_000), 25_000);
+        assert_eq!(fee_schedule.sol_fee(1_000_000, 90_000), 40_000);
+    }
+
+    #[test]
+    fn min_fee_never_exceeds_fee_cap() {
+        let mut fee_schedule = flat_fee_schedule();
+        fee_schedule.sol_min_fee = u64::MAX;
+
+        // at most MAX_TOKEN_FEE_BPS (10%) of the amount
+        assert_eq!(fee_schedule.sol_fee(1_000_000, 0), 100_000);
+        assert_eq!(fee_schedule.sol_fee(9, 0), 0);
+    }
+
+    #[test]
+    fn token_fee_uses_mint_override() {
+        let mint = Pubkey::new_unique();
+        let mut fee_schedule = flat_fee_schedule();
+        fee_schedule.mint_fees = vec![MintFee {
+            mint,
+            fee_bps: 100,
+            min_fee: 50,
+            max_fee: 0,
+        }];
+
+        assert_eq!(fee_schedule.token_fee(&mint, 1_000_000, 25_000), 10_000);
+        assert_eq!(fee_schedule.token_fee(&mint, 1_000, 25), 50);
+        assert_eq!(fee_schedule.token_fee(&mint, 100, 2), 10);
+
+        // other mints pay the flat fee
+        assert_eq!(
+            fee_schedule.token_fee(&Pubkey::new_unique(), 1_000_000, 25_000),
+            25_000
+        );
+    }
+
+    #[test]
+    fn applied_fee_only_lowers_older_wills() {
+        let mut fee_schedule = flat_fee_schedule();
+        fee_schedule.effective_at = 100;
+
+        assert_eq!(fee_schedule.applied_fee(99, 30, 20), 20);
+        assert_eq!(fee_schedule.applied_fee(99, 10, 20), 10);
+        assert_eq!(fee_schedule.applied_fee(100, 30, 20), 30);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{constants::*, state::*};
+use anchor_lang::prelude::*;
+
+/// Creation and heartbeat time of fixture wills
+pub const START: i64 = 1_700_000_000;
+
+/// Active will with 2.5% snapshotted fees and no optional features set
+pub fn will() -> Will {
+    Will {
+        testator: Pubkey::new_unique(),
+        beneficiary: Pubkey::new_unique(),
+        vault: Pubkey::new_unique(),
+        heartbeat_period: 30 * SECONDS_PER_DAY,
+        status: WillStatus::Active,
+        created_at: START,
+        last_heartbeat: START,
+        trigger_at: None,
+        bump: 0,
+        vault_bump: 0,
+        staked_lamports: 0,
+        action_count: 0,
+        next_action_index: 0,
+        contingent_beneficiary: None,
+        claim_deadline: 0,
+        claimed_by_contingent: false,
+        fallback: None,
+        unclaimed_expiry: 0,
+        payout_schedule: PayoutSchedule::Immediate,
+        claimed_so_far: 0,
+        sol_vesting_total: 0,
+        release_not_before: None,
+        executor: None,
+        executor_fee_bps: 0,
+        paused_seconds_at_heartbeat: 0,
+        fee_snapshot: Some(FeeSnapshot {
+            token_fee_bps: 250,
+            nft_fee_lamports: NFT_SERVICE_FEE_LAMPORTS,
+        }),
+        subscription_fee_lamports: 0,
+        subscription_paid_until: 0,
+        referrer: None,
+        referrer_share_bps: 0,
+        contributed_lamports: 0,
+        reserved: [0; 88],
+    }
+}
+
+/// Unpaused config with 2.5% fees
+pub fn config() -> Config {
+    Config {
+        authority: Pubkey::new_unique(),
+        fee_vault: Pubkey::new_unique(),
+        token_fee_bps: 250,
+        nft_fee_lamports: NFT_SERVICE_FEE_LAMPORTS,
+        min_heartbeat_period: SECONDS_PER_DAY,
+        max_heartbeat_period: SECONDS_PER_YEAR,
+        min_heartbeat_interval: SECONDS_PER_HOUR,
+        pause_flags: 0,
+        bump: 0,
+        paused_since: 0,
+        total_paused_seconds: 0,
+        last_pause_ended_at: 0,
+        pause_flags_migrated: true,
+        reserved: [0; 3],
+    }
+}
+
+/// Fee schedule without tiers or overrides (will fees apply)
+pub fn flat_fee_schedule() -> FeeSchedule {
+    FeeSchedule {
+        sol_tiers: Vec::new(),
+        sol_min_fee: 0,
+        sol_max_fee: 0,
+        mint_fees: Vec::new(),
+        annual_fee_lamports: 0,
+        referrer_share_bps: 0,
+        effective_at: 0,
+        bump: 0,
+    }
+}
@@ -1,5 +1,32 @@
+pub mod admin_proposal;
+pub mod admin_roles;
+pub mod admin_set;
+pub mod authority;
+pub mod claim_record;
 pub mod config;
+pub mod contribution;
+pub mod fee_schedule;
+pub mod pending_config_change;
+pub mod pending_fee_schedule;
+pub mod program_allowlist;
 pub mod will;
+pub mod will_action;
+pub mod will_inventory;
 
+pub use admin_proposal::*;
+pub use admin_roles::*;
+pub use admin_set::*;
+pub use authority::*;
+pub use claim_record::*;
 pub use config::*;
+pub use contribution::*;
+pub use fee_schedule::*;
+pub use pending_config_change::*;
+pub use pending_fee_schedule::*;
+pub use program_allowlist::*;
 pub use will::*;
+pub use will_action::*;
+pub use will_inventory::*;
+
+#[cfg(test)]
+pub(crate) mod fixtures;
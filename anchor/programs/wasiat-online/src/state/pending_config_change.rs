@@ -0,0 +1,88 @@
+use crate::{error::AppError, state::Config};
+use anchor_lang::prelude::*;
+
+/// Fee and heartbeat bound changes, applied to config only after the timelock
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Default)]
+pub struct ConfigChange {
+    pub token_fee_bps: Option<u16>,
+    pub nft_fee_lamports: Option<u64>,
+    pub min_heartbeat_period: Option<u32>,
+    pub max_heartbeat_period: Option<u32>,
+    pub min_heartbeat_interval: Option<u32>,
+}
+
+impl ConfigChange {
+    pub fn is_empty(&self) -> bool {
+        self.token_fee_bps.is_none()
+            && self.nft_fee_lamports.is_none()
+            && self.min_heartbeat_period.is_none()
+            && self.max_heartbeat_period.is_none()
+            && self.min_heartbeat_interval.is_none()
+    }
+
+    /// Fee fields need the fee manager
+    pub fn changes_fees(&self) -> bool {
+        self.token_fee_bps.is_some() || self.nft_fee_lamports.is_some()
+    }
+
+    /// Heartbeat bound fields need the config owner
+    pub fn changes_heartbeat_bounds(&self) -> bool {
+        self.min_heartbeat_period.is_some()
+            || self.max_heartbeat_period.is_some()
+            || self.min_heartbeat_interval.is_some()
+    }
+
+    /// Applies provided fields to `config`, validating the resulting bounds
+    pub fn apply(&self, config: &mut Config) -> Result<()> {
+        if let Some(token_fee_bps) = self.token_fee_bps {
+            config.token_fee_bps = token_fee_bps;
+        }
+
+        if let Some(nft_fee_lamports) = self.nft_fee_lamports {
+            config.nft_fee_lamports = nft_fee_lamports;
+        }
+
+        // validate fee caps
+        config.current_fees().validate()?;
+
+        if let Some(min_heartbeat_period) = self.min_heartbeat_period {
+            require!(
+                min_heartbeat_period > 0,
+                AppError::InvalidMinimumHeartbeatPeriod
+            );
+            config.min_heartbeat_period = min_heartbeat_period;
+        }
+
+        if let Some(max_heartbeat_period) = self.max_heartbeat_period {
+            config.max_heartbeat_period = max_heartbeat_period;
+        }
+
+        // validate bounds against the resulting minimum
+        if self.min_heartbeat_period.is_some() || self.max_heartbeat_period.is_some() {
+            require!(
+                config.max_heartbeat_period > config.min_heartbeat_period,
+                AppError::InvalidMaximumHeartbeatPeriod
+            );
+        }
+
+        if let Some(min_heartbeat_interval) = self.min_heartbeat_interval {
+            require!(
+                min_heartbeat_interval > 0,
+                AppError::InvalidMinimumHeartbeatInterval
+            );
+            config.min_heartbeat_interval = min_heartbeat_interval;
+        }
+
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PendingConfigChange {
+    pub proposer: Pubkey, // authority that queued the change (receives rent back)
+    pub change: ConfigChange, // fields to apply
+    pub queued_at: i64,   // timestamp
+    pub effective_at: i64, // timestamp - executable by anyone from then
+    pub bump: u8,         // pda bump
+}
@@ -0,0 +1,37 @@
+use crate::{
+    constants::*,
+    state::{FeeSchedule, FeeTier, MintFee},
+};
+use anchor_lang::prelude::*;
+
+/// Fee schedule replacement, applied only after the timelock
+#[account]
+#[derive(InitSpace)]
+pub struct PendingFeeSchedule {
+    pub proposer: Pubkey, // fee manager that queued the schedule (receives rent back)
+    #[max_len(MAX_FEE_TIERS)]
+    pub sol_tiers: Vec<FeeTier>,
+    pub sol_min_fee: u64,
+    pub sol_max_fee: u64,
+    #[max_len(MAX_MINT_FEE_OVERRIDES)]
+    pub mint_fees: Vec<MintFee>,
+    pub annual_fee_lamports: u64,
+    pub referrer_share_bps: u16,
+    pub queued_at: i64,    // timestamp
+    pub effective_at: i64, // timestamp - executable by anyone from then
+    pub bump: u8,          // pda bump
+}
+
+impl PendingFeeSchedule {
+    /// Writes queued terms into `fee_schedule`, validating the resulting schedule
+    pub fn apply(&self, fee_schedule: &mut FeeSchedule) -> Result<()> {
+        fee_schedule.sol_tiers = self.sol_tiers.clone();
+        fee_schedule.sol_min_fee = self.sol_min_fee;
+        fee_schedule.sol_max_fee = self.sol_max_fee;
+        fee_schedule.mint_fees = self.mint_fees.clone();
+        fee_schedule.annual_fee_lamports = self.annual_fee_lamports;
+        fee_schedule.referrer_share_bps = self.referrer_share_bps;
+
+        fee_schedule.validate()
+    }
+}
@@ -0,0 +1,16 @@
+use crate::constants::*;
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramAllowlist {
+    #[max_len(MAX_ALLOWED_PROGRAMS)]
+    pub programs: Vec<Pubkey>, // programs will actions may invoke
+    pub bump: u8, // pda bump
+}
+
+impl ProgramAllowlist {
+    pub fn is_allowed(&self, program_id: &Pubkey) -> bool {
+        self.programs.contains(program_id)
+    }
+}
@@ -1,3 +1,8 @@
+use crate::{
+    constants::*,
+    error::AppError,
+    state::{Config, FeeSchedule},
+};
 use anchor_lang::prelude::*;
 
 #[account]
@@ -21,8 +26,354 @@ pub struct Will {
     pub bump: u8,       // pda bump
     pub vault_bump: u8, // vault pda bump
 
+    // staking
+    pub staked_lamports: u64, // vault sol delegated through vault stake account
+
+    // actions
+    pub action_count: u8,      // pending will actions
+    pub next_action_index: u8, // pda index for next will action
+
+    // contingent beneficiary
+    pub contingent_beneficiary: Option<Pubkey>, // backup claimant
+    pub claim_deadline: u32,                    // seconds after claims open, then contingent claims
+    pub claimed_by_contingent: bool,            // estate taken over by contingent beneficiary
+
+    // unclaimed estate
+    pub fallback: Option<Pubkey>, // receives estate left unclaimed after expiry
+    pub unclaimed_expiry: u32,    // seconds after claims open before estate can be reclaimed
+
+    // payout
+    pub payout_schedule: PayoutSchedule, // vesting of sol and spl token claims from trigger
+    pub claimed_so_far: u64,             // lamports released from vault by sol claims (incl. fees)
+    pub sol_vesting_total: u64, // lamports vesting under payout schedule (snapshot at trigger)
+
+    // release gate
+    pub release_not_before: Option<i64>, // timestamp - claims rejected until then, even after trigger
+
+    // executor
+    pub executor: Option<Pubkey>, // may distribute estate to beneficiary after trigger
+    pub executor_fee_bps: u16,    // executor compensation in basis points of distributed amount
+
+    // pause accounting
+    pub paused_seconds_at_heartbeat: u64, // config trigger paused seconds at last heartbeat
+
+    // fees
+    pub fee_snapshot: Option<FeeSnapshot>, // config fees at creation (or at migration for older wills)
+    pub subscription_fee_lamports: u64,    // annual fee instead of claim bps (0 if not subscribed)
+    pub subscription_paid_until: i64,      // timestamp - subscription paid up to
+
+    // referral
+    pub referrer: Option<Pubkey>, // partner that onboarded testator
+    pub referrer_share_bps: u16, // share of claim service fees paid to referrer (fixed at creation)
+
+    // contributions
+    pub contributed_lamports: u64, // vault SOL contributed by third parties (not withdrawable by testator)
+
     // reserved for future upgrades
-    pub reserved: [u8; 64], // reserved space
+    pub reserved: [u8; 88], // reserved space (account grew from 200 to 456 bytes, older wills resized by migrate_will)
+}
+
+impl Will {
+    /// Fees charged on this will, config fees for wills not migrated yet
+    /// (wills with a paid-up subscription pay no bps fee at claim)
+    pub fn fees(&self, config: &Config) -> FeeSnapshot {
+        let mut fees = self.fee_snapshot.unwrap_or_else(|| config.current_fees());
+        if self.is_subscription_active() {
+            fees.token_fee_bps = 0;
+        }
+        fees
+    }
+
+    /// SOL service fee on `amount` under the fee schedule (none while subscription is paid up)
+    pub fn sol_service_fee(&self, config: &Config, fee_schedule: &FeeSchedule, amount: u64) -> u64 {
+        if self.is_subscription_active() {
+            return 0;
+        }
+        let token_fee_bps = self.fees(config).token_fee_bps;
+        let flat_fee = (amount as u128 * token_fee_bps as u128 / 10_000) as u64;
+        let scheduled_fee = fee_schedule.sol_fee(amount, flat_fee);
+        fee_schedule.applied_fee(self.created_at, scheduled_fee, flat_fee)
+    }
+
+    /// SPL token service fee on `amount` of `mint` under the fee schedule (none for subscribed wills)
+    pub fn token_service_fee(
+        &self,
+        config: &Config,
+        fee_schedule: &FeeSchedule,
+        mint: &Pubkey,
+        amount: u64,
+    ) -> u64 {
+        if self.is_subscription_active() {
+            return 0;
+        }
+        let token_fee_bps = self.fees(config).token_fee_bps;
+        let flat_fee = (amount as u128 * token_fee_bps as u128 / 10_000) as u64;
+        let scheduled_fee = fee_schedule.token_fee(mint, amount, flat_fee);
+        fee_schedule.applied_fee(self.created_at, scheduled_fee, flat_fee)
+    }
+
+    /// Referrer share of `service_fee` (0 without referrer)
+    pub fn referrer_fee(&self, service_fee: u64) -> u64 {
+        if self.referrer.is_none() {
+            return 0;
+        }
+        (service_fee as u128 * self.referrer_share_bps as u128 / 10_000) as u64
+    }
+
+    /// Validates the account receiving the referrer share belongs to the will referrer
+    pub fn validate_referrer(&self, referrer: Pubkey) -> Result<()> {
+        require!(self.referrer == Some(referrer), AppError::ReferrerMismatch);
+
+        Ok(())
+    }
+
+    pub fn is_subscribed(&self) -> bool {
+        self.subscription_fee_lamports > 0
+    }
+
+    /// Subscribed and paid through the last heartbeat, the silence before trigger is covered
+    pub fn is_subscription_active(&self) -> bool {
+        self.is_subscribed() && self.subscription_paid_until >= self.last_heartbeat
+    }
+
+    /// Subscription fee owed for time past `subscription_paid_until`, pro-rated per second
+    pub fn subscription_due(&self, current_time: i64) -> u64 {
+        let unpaid_seconds = current_time
+            .saturating_sub(self.subscription_paid_until)
+            .max(0);
+        (self.subscription_fee_lamports as u128 * unpaid_seconds as u128 / SECONDS_PER_YEAR as u128)
+            as u64
+    }
+
+    /// Settles subscription through `current_time`, returns the fee due for it
+    pub fn renew_subscription(&mut self, current_time: i64) -> u64 {
+        let subscription_fee = self.subscription_due(current_time);
+        self.subscription_paid_until = self.subscription_paid_until.max(current_time);
+        subscription_fee
+    }
+
+    /// Timestamp heartbeat runs out, after which the will can be triggered
+    /// (extended by trigger pause time since last heartbeat, `paused_seconds` from config)
+    pub fn expiry_time(&self, paused_seconds: u64) -> i64 {
+        let paused_since_heartbeat =
+            paused_seconds.saturating_sub(self.paused_seconds_at_heartbeat);
+        self.last_heartbeat + self.heartbeat_period as i64 + paused_since_heartbeat as i64
+    }
+
+    /// Restarts heartbeat timer
+    pub fn record_heartbeat(&mut self, current_time: i64, paused_seconds: u64) {
+        self.last_heartbeat = current_time;
+        self.paused_seconds_at_heartbeat = paused_seconds;
+    }
+
+    /// Applies a deposit: activates new wills, rejects expired ones, refreshes heartbeat on request
+    pub fn apply_deposit(
+        &mut self,
+        as_heartbeat: bool,
+        config: &Config,
+        current_time: i64,
+    ) -> Result<()> {
+        let paused_seconds = config.paused_seconds(current_time);
+
+        match self.status {
+            // first deposit starts the heartbeat timer
+            WillStatus::Created => {
+                self.status = WillStatus::Active;
+                self.record_heartbeat(current_time, paused_seconds);
+            }
+            WillStatus::Active => {
+                // validate will not expired awaiting trigger
+                require!(
+                    current_time < self.expiry_time(paused_seconds),
+                    AppError::WillAlreadyExpired
+                );
+
+                if as_heartbeat {
+                    // validate heartbeat cooldown, same as send_heartbeat
+                    require!(
+                        current_time - self.last_heartbeat >= config.min_heartbeat_interval as i64,
+                        AppError::HeartbeatTooFrequent
+                    );
+                    // validate no subscription renewal due, only send_heartbeat charges it
+                    require!(
+                        self.subscription_due(current_time) == 0,
+                        AppError::SubscriptionRenewalDue
+                    );
+                    self.record_heartbeat(current_time, paused_seconds);
+                }
+            }
+            _ => return err!(AppError::InvalidWillStatus),
+        }
+
+        Ok(())
+    }
+
+    /// Validates `claimant` can claim now, returns true when claiming as contingent beneficiary
+    pub fn claimant_is_contingent(&self, claimant: Pubkey, current_time: i64) -> Result<bool> {
+        // validate release date reached
+        if let Some(release_not_before) = self.release_not_before {
+            require!(
+                current_time >= release_not_before,
+                AppError::ReleaseDateNotReached
+            );
+        }
+
+        // primary beneficiary, unless contingent beneficiary has taken over
+        if claimant == self.beneficiary {
+            require!(
+                !self.claimed_by_contingent,
+                AppError::ClaimedByContingentBeneficiary
+            );
+            return Ok(false);
+        }
+
+        // contingent beneficiary
+        require!(
+            self.contingent_beneficiary == Some(claimant),
+            AppError::Unauthorized
+        );
+
+        // validate claim deadline has passed since claims opened
+        let claims_open_at = self.claims_open_at().ok_or(AppError::WillNotTriggered)?;
+        require!(
+            current_time >= claims_open_at + self.claim_deadline as i64,
+            AppError::ClaimDeadlineNotReached
+        );
+
+        // validate primary beneficiary has not claimed within deadline
+        require!(
+            self.status == WillStatus::Triggered || self.claimed_by_contingent,
+            AppError::ClaimedByBeneficiary
+        );
+
+        Ok(true)
+    }
+
+    /// Timestamp claims open: trigger, or release date if later
+    pub fn claims_open_at(&self) -> Option<i64> {
+        self.trigger_at
+            .map(|trigger_at| match self.release_not_before {
+                Some(release_not_before) => trigger_at.max(release_not_before),
+                None => trigger_at,
+            })
+    }
+
+    /// Whether a triggered estate was left unclaimed past its expiry
+    pub fn is_unclaimed_expired(&self, current_time: i64) -> bool {
+        match (self.fallback, self.claims_open_at()) {
+            (Some(_), Some(claims_open_at)) => {
+                current_time >= claims_open_at + self.unclaimed_expiry as i64
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the payout schedule has fully vested
+    pub fn is_fully_vested(&self, current_time: i64) -> bool {
+        match self.trigger_at {
+            Some(trigger_at) => current_time >= trigger_at + self.payout_schedule.duration() as i64,
+            None => false,
+        }
+    }
+
+    /// Amount releasable now out of `remaining`, given `claimed_so_far` already released
+    /// of a snapshotted vesting `total` (everything remaining once fully vested)
+    pub fn releasable_amount(
+        &self,
+        total: u64,
+        remaining: u64,
+        claimed_so_far: u64,
+        current_time: i64,
+    ) -> Result<u64> {
+        let trigger_at = self.trigger_at.ok_or(AppError::WillNotTriggered)?;
+        let elapsed = current_time.saturating_sub(trigger_at);
+
+        if self.is_fully_vested(current_time) {
+            return Ok(remaining);
+        }
+
+        let vested = self.payout_schedule.vested_amount(total, elapsed);
+
+        Ok(vested.saturating_sub(claimed_so_far).min(remaining))
+    }
+}
+
+/// Service fees fixed for a will
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct FeeSnapshot {
+    pub token_fee_bps: u16,    // basis points for SOL and SPL token
+    pub nft_fee_lamports: u64, // flat fee for NFTs
+}
+
+impl FeeSnapshot {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.token_fee_bps <= MAX_TOKEN_FEE_BPS,
+            AppError::InvalidTokenFee
+        );
+        require!(
+            self.nft_fee_lamports <= MAX_NFT_FEE_LAMPORTS,
+            AppError::InvalidNftFee
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum PayoutSchedule {
+    Immediate,                             // 0 - everything claimable at trigger
+    Linear { cliff: u32, duration: u32 },  // 1 - nothing before cliff, linear until duration
+    Tranches { count: u8, interval: u32 }, // 2 - equal tranches every interval, first at trigger
+}
+
+impl PayoutSchedule {
+    pub fn validate(&self) -> Result<()> {
+        match *self {
+            PayoutSchedule::Immediate => {}
+            PayoutSchedule::Linear { cliff, duration } => {
+                require!(
+                    duration > 0 && cliff <= duration,
+                    AppError::InvalidPayoutSchedule
+                );
+            }
+            PayoutSchedule::Tranches { count, interval } => {
+                require!(count > 0 && interval > 0, AppError::InvalidPayoutSchedule);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Seconds after trigger until everything is vested
+    pub fn duration(&self) -> u32 {
+        match *self {
+            PayoutSchedule::Immediate => 0,
+            PayoutSchedule::Linear { duration, .. } => duration,
+            PayoutSchedule::Tranches { count, interval } => {
+                interval.saturating_mul(count.saturating_sub(1) as u32)
+            }
+        }
+    }
+
+    /// Portion of `total` vested `elapsed` seconds after trigger
+    pub fn vested_amount(&self, total: u64, elapsed: i64) -> u64 {
+        let (vested_parts, parts) = match *self {
+            PayoutSchedule::Immediate => return total,
+            PayoutSchedule::Linear { cliff, duration } => {
+                if elapsed < cliff as i64 {
+                    return 0;
+                }
+                (elapsed.min(duration as i64) as u64, duration as u64)
+            }
+            PayoutSchedule::Tranches { count, interval } => {
+                let released = elapsed / interval as i64 + 1;
+                (released.min(count as i64) as u64, count as u64)
+            }
+        };
+
+        (total as u128 * vested_parts as u128 / parts as u128) as u64
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
@@ -32,4 +383,198 @@ pub enum WillStatus {
     Triggered, // 2 - timer runs out, can be claimed
     Claimed,   // 3 - already claimed by beneficiary
     Withdrawn, // 4 - assets withdrawn by testator
+    Expired,   // 5 - unclaimed estate returned to fallback
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::fixtures::*;
+
+    const YEAR: i64 = SECONDS_PER_YEAR as i64;
+    const SOL: u64 = 1_000_000_000;
+
+    fn triggered_will(payout_schedule: PayoutSchedule) -> Will {
+        let mut will = will();
+        will.status = WillStatus::Triggered;
+        will.trigger_at = Some(START);
+        will.payout_schedule = payout_schedule;
+        will
+    }
+
+    fn subscribed_will() -> Will {
+        let mut will = will();
+        will.subscription_fee_lamports = 1_000_000_000;
+        will.subscription_paid_until = START + YEAR;
+        will
+    }
+
+    #[test]
+    fn will_account_size_matches_migration_target() {
+        // migrate_will grows 200 byte baseline wills to this size
+        assert_eq!(8 + Will::INIT_SPACE, 456);
+    }
+
+    #[test]
+    fn subscription_due_pro_rates_unpaid_time() {
+        let will = subscribed_will();
+        assert_eq!(will.subscription_due(START), 0);
+        assert_eq!(will.subscription_due(START + YEAR), 0);
+        assert_eq!(will.subscription_due(START + YEAR + YEAR / 2), 500_000_000);
+        assert_eq!(will.subscription_due(START + 2 * YEAR), 1_000_000_000);
+    }
+
+    #[test]
+    fn subscribed_will_pays_no_claim_fee_after_heartbeat_past_year_one() {
+        let (config, fee_schedule) = (config(), flat_fee_schedule());
+        let mut will = subscribed_will();
+
+        // heartbeat half a year after the first paid year settles arrears
+        let heartbeat_at = START + YEAR + YEAR / 2;
+        assert_eq!(will.renew_subscription(heartbeat_at), 500_000_000);
+        will.record_heartbeat(heartbeat_at, 0);
+
+        // claim after the heartbeat period ran out is still covered
+        assert!(will.is_subscription_active());
+        assert_eq!(will.sol_service_fee(&config, &fee_schedule, SOL), 0);
+        assert_eq!(will.fees(&config).token_fee_bps, 0);
+    }
+
+    #[test]
+    fn unsubscribed_will_pays_claim_fee() {
+        let (config, fee_schedule) = (config(), flat_fee_schedule());
+        let will = will();
+
+        assert!(!will.is_subscription_active());
+        assert_eq!(
+            will.sol_service_fee(&config, &fee_schedule, SOL),
+            25_000_000
+        );
+    }
+
+    #[test]
+    fn fee_schedule_never_raises_fees_of_older_wills() {
+        let config = config();
+        let mut fee_schedule = flat_fee_schedule();
+        fee_schedule.sol_min_fee = 50_000_000;
+        fee_schedule.effective_at = START + 1;
+
+        // created before the schedule: keeps its snapshotted 2.5%
+        let older = will();
+        assert_eq!(
+            older.sol_service_fee(&config, &fee_schedule, SOL),
+            25_000_000
+        );
+
+        // created after the schedule: pays its minimum fee
+        let mut newer = will();
+        newer.created_at = START + 2;
+        assert_eq!(
+            newer.sol_service_fee(&config, &fee_schedule, SOL),
+            50_000_000
+        );
+
+        // lower schedule fees apply to older wills too
+        fee_schedule.sol_min_fee = 0;
+        fee_schedule.sol_max_fee = 10_000_000;
+        assert_eq!(
+            older.sol_service_fee(&config, &fee_schedule, SOL),
+            10_000_000
+        );
+    }
+
+    #[test]
+    fn releasable_amount_requires_trigger() {
+        assert!(will().releasable_amount(SOL, SOL, 0, START).is_err());
+    }
+
+    #[test]
+    fn immediate_payout_releases_everything_remaining() {
+        let will = triggered_will(PayoutSchedule::Immediate);
+        assert_eq!(will.releasable_amount(SOL, SOL, 0, START).unwrap(), SOL);
+
+        // later inflows are released too
+        assert_eq!(
+            will.releasable_amount(SOL, 2 * SOL, 0, START).unwrap(),
+            2 * SOL
+        );
+    }
+
+    #[test]
+    fn linear_payout_vests_after_cliff() {
+        let day = SECONDS_PER_DAY as i64;
+        let will = triggered_will(PayoutSchedule::Linear {
+            cliff: 10 * SECONDS_PER_DAY,
+            duration: 100 * SECONDS_PER_DAY,
+        });
+
+        // nothing before cliff, then pro rata from trigger
+        assert_eq!(
+            will.releasable_amount(SOL, SOL, 0, START + 9 * day)
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            will.releasable_amount(SOL, SOL, 0, START + 10 * day)
+                .unwrap(),
+            100_000_000
+        );
+
+        // already claimed portion is not released again
+        assert_eq!(
+            will.releasable_amount(SOL, SOL / 2, SOL / 2, START + 75 * day)
+                .unwrap(),
+            250_000_000
+        );
+
+        // everything remaining once fully vested
+        assert_eq!(
+            will.releasable_amount(SOL, SOL / 2 + 7, SOL / 2, START + 100 * day)
+                .unwrap(),
+            SOL / 2 + 7
+        );
+    }
+
+    #[test]
+    fn tranche_payout_releases_first_tranche_at_trigger() {
+        let interval = 30 * SECONDS_PER_DAY;
+        let will = triggered_will(PayoutSchedule::Tranches { count: 4, interval });
+
+        assert_eq!(
+            will.releasable_amount(SOL, SOL, 0, START).unwrap(),
+            250_000_000
+        );
+        assert_eq!(
+            will.releasable_amount(SOL, SOL, 0, START + interval as i64 - 1)
+                .unwrap(),
+            250_000_000
+        );
+        assert_eq!(
+            will.releasable_amount(SOL, 3 * SOL / 4, SOL / 4, START + interval as i64)
+                .unwrap(),
+            250_000_000
+        );
+
+        // capped by what is left in the vault
+        assert_eq!(
+            will.releasable_amount(SOL, 100, SOL / 4, START + interval as i64)
+                .unwrap(),
+            100
+        );
+    }
+
+    #[test]
+    fn referrer_fee_splits_service_fee_by_snapshotted_share() {
+        let mut will = will();
+        assert_eq!(will.referrer_fee(25_000_000), 0);
+
+        let referrer = Pubkey::new_unique();
+        will.referrer = Some(referrer);
+        will.referrer_share_bps = 2_000;
+        assert_eq!(will.referrer_fee(25_000_000), 5_000_000);
+        assert_eq!(will.referrer_fee(3), 0);
+
+        assert!(will.validate_referrer(referrer).is_ok());
+        assert!(will.validate_referrer(Pubkey::new_unique()).is_err());
+    }
 }
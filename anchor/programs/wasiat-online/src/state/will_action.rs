@@ -0,0 +1,22 @@
+use crate::constants::*;
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct WillAction {
+    pub will: Pubkey,       // will owning this action
+    pub index: u8,          // pda index within will
+    pub program_id: Pubkey, // target program (must be allowlisted)
+    #[max_len(MAX_ACTION_ACCOUNTS)]
+    pub accounts: Vec<ActionAccountMeta>, // account metas, vault is the only signer
+    #[max_len(MAX_ACTION_DATA_LEN)]
+    pub data: Vec<u8>, // instruction data
+    pub bump: u8,           // pda bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct ActionAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
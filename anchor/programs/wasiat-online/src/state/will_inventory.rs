@@ -0,0 +1,171 @@
+use crate::{constants::*, error::AppError};
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token::{spl_token::native_mint, Mint};
+
+#[account]
+pub struct WillInventory {
+    pub will: Pubkey,                 // will owning this inventory
+    pub bump: u8,                     // pda bump
+    pub entries: Vec<InventoryEntry>, // one per deposited asset (SOL under native mint)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct InventoryEntry {
+    pub mint: Pubkey, // token mint (native mint for SOL), stake account or authority target
+    pub kind: AssetKind, // asset kind
+    pub deposited: u64, // amount deposited into vault (net of testator withdrawals)
+    pub claimed: u64, // amount released from vault to beneficiary, executor or fallback (incl. fees)
+    pub contributed: u64, // third-party contributions held in vault for refund
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum AssetKind {
+    Fungible,  // 0 - SOL or spl token
+    Nft,       // 1 - spl nft (supply 1, decimals 0)
+    Stake,     // 2 - native stake account (amounts in lamports)
+    Authority, // 3 - upgrade, mint or freeze authority (one per authority)
+}
+
+impl AssetKind {
+    pub fn of(mint: &Mint) -> Self {
+        if mint.supply == NFT_AMOUNT as u64 && mint.decimals == 0 {
+            AssetKind::Nft
+        } else {
+            AssetKind::Fungible
+        }
+    }
+}
+
+/// Movement of an asset in or out of the vault
+#[derive(Clone, Copy)]
+pub enum InventoryChange {
+    Deposit(u64),
+    Claim(u64),
+    Withdraw(u64),
+    Contribute(u64),
+    Refund(u64),
+}
+
+impl WillInventory {
+    /// Account size holding `entry_count` entries
+    pub fn space(entry_count: usize) -> usize {
+        8 + 32 + 1 + 4 + entry_count * InventoryEntry::INIT_SPACE
+    }
+
+    /// Contributions of `mint` held in vault for refund
+    pub fn contributed(&self, mint: &Pubkey) -> u64 {
+        self.entries
+            .iter()
+            .filter(|entry| entry.mint == *mint)
+            .map(|entry| entry.contributed)
+            .sum()
+    }
+
+    /// Records SOL movement (tracked under native mint)
+    pub fn record_sol<'info>(
+        inventory: &mut Account<'info, WillInventory>,
+        payer: AccountInfo<'info>,
+        system_program: AccountInfo<'info>,
+        change: InventoryChange,
+    ) -> Result<()> {
+        Self::record(
+            inventory,
+            payer,
+            system_program,
+            native_mint::ID,
+            AssetKind::Fungible,
+            change,
+        )
+    }
+
+    /// Records asset movement for `mint`, growing the account (paid by `payer`) for new assets
+    pub fn record<'info>(
+        inventory: &mut Account<'info, WillInventory>,
+        payer: AccountInfo<'info>,
+        system_program: AccountInfo<'info>,
+        mint: Pubkey,
+        kind: AssetKind,
+        change: InventoryChange,
+    ) -> Result<()> {
+        let index = match inventory
+            .entries
+            .iter()
+            .position(|entry| entry.mint == mint && entry.kind == kind)
+        {
+            Some(index) => index,
+            None => {
+                require!(
+                    inventory.entries.len() < MAX_INVENTORY_ENTRIES,
+                    AppError::InventoryFull
+                );
+
+                // grow account by one entry
+                let inventory_info = inventory.to_account_info();
+                let new_size = Self::space(inventory.entries.len() + 1);
+                let required_rent = Rent::get()?.minimum_balance(new_size);
+                let rent_top_up = required_rent.saturating_sub(inventory_info.lamports());
+                if rent_top_up > 0 {
+                    let rent_transfer_accounts = Transfer {
+                        from: payer,
+                        to: inventory_info.clone(),
+                    };
+
+                    let rent_transfer_ctx = CpiContext::new(system_program, rent_transfer_accounts);
+
+                    transfer(rent_transfer_ctx, rent_top_up)?;
+                }
+                inventory_info.resize(new_size)?;
+
+                inventory.entries.push(InventoryEntry {
+                    mint,
+                    kind,
+                    deposited: 0,
+                    claimed: 0,
+                    contributed: 0,
+                });
+                inventory.entries.len() - 1
+            }
+        };
+
+        let entry = &mut inventory.entries[index];
+        match change {
+            InventoryChange::Deposit(amount) => {
+                entry.deposited = entry
+                    .deposited
+                    .checked_add(amount)
+                    .ok_or(AppError::Overflow)?;
+            }
+            InventoryChange::Claim(amount) => {
+                entry.claimed = entry
+                    .claimed
+                    .checked_add(amount)
+                    .ok_or(AppError::Overflow)?;
+            }
+            InventoryChange::Withdraw(amount) => {
+                entry.deposited = entry.deposited.saturating_sub(amount)
+            }
+            InventoryChange::Contribute(amount) => {
+                entry.deposited = entry
+                    .deposited
+                    .checked_add(amount)
+                    .ok_or(AppError::Overflow)?;
+                entry.contributed = entry
+                    .contributed
+                    .checked_add(amount)
+                    .ok_or(AppError::Overflow)?;
+            }
+            InventoryChange::Refund(amount) => {
+                entry.deposited = entry.deposited.saturating_sub(amount);
+                entry.contributed = entry
+                    .contributed
+                    .checked_sub(amount)
+                    .ok_or(AppError::Underflow)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,587 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { BankrunProvider, startAnchor } from "anchor-bankrun";
+import { Clock, ProgramTestContext } from "solana-bankrun";
+import { WasiatOnline } from "../target/types/wasiat_online";
+import IDL from "../target/idl/wasiat_online.json";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  StakeProgram,
+  VoteProgram,
+  VoteInit,
+  Authorized,
+  Lockup,
+  STAKE_CONFIG_ID,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { expect } from "chai";
+
+// Runs on an in-process bank so wills expire by warping the clock instead of waiting
+describe("Wasiat Online Post-Trigger Tests", () => {
+  const HEARTBEAT_PERIOD = 86_400; // 1 day, the program minimum
+  const TRIGGER_GRACE_PERIOD = 300; // 5 minutes
+
+  let context: ProgramTestContext;
+  let provider: BankrunProvider;
+  let program: Program<WasiatOnline>;
+
+  let authority: Keypair;
+  let keeper: Keypair;
+  let feeVaultPda: PublicKey;
+  let rent: number;
+
+  const fund = (...keypairs: Keypair[]) => {
+    for (const keypair of keypairs) {
+      context.setAccount(keypair.publicKey, {
+        lamports: 10 * LAMPORTS_PER_SOL,
+        data: Buffer.alloc(0),
+        owner: SystemProgram.programId,
+        executable: false,
+      });
+    }
+  };
+
+  const balance = async (account: PublicKey) => Number(await context.banksClient.getBalance(account));
+
+  // Moves the clock forward by `seconds`, on a new slot so repeated transactions get a fresh blockhash
+  const warp = async (seconds: number) => {
+    const clock = await context.banksClient.getClock();
+    context.warpToSlot(clock.slot + 1n);
+    context.setClock(
+      new Clock(
+        clock.slot + 1n,
+        clock.epochStartTimestamp,
+        clock.epoch,
+        clock.leaderScheduleEpoch,
+        clock.unixTimestamp + BigInt(seconds)
+      )
+    );
+  };
+
+  // Bankrun errors carry no logs, so match on the idl error code
+  const expectError = async (tx: Promise<unknown>, name: string) => {
+    const { code } = IDL.errors.find((error) => error.name === name);
+    try {
+      await tx;
+    } catch (error) {
+      expect(error.error?.errorCode?.number ?? error.code, `${error}`).to.equal(code);
+      return;
+    }
+    expect.fail(`Should have thrown ${name}`);
+  };
+
+  // Creates an active will for a fresh testator and beneficiary, optionally with a referrer
+  const createWill = async (referrer: PublicKey | null = null, depositLamports = LAMPORTS_PER_SOL) => {
+    const owner = Keypair.generate();
+    const heir = Keypair.generate();
+    fund(owner, heir);
+
+    const [will] = PublicKey.findProgramAddressSync(
+      [Buffer.from("will"), owner.publicKey.toBuffer(), heir.publicKey.toBuffer()],
+      program.programId
+    );
+    const [vault] = PublicKey.findProgramAddressSync([Buffer.from("vault"), will.toBuffer()], program.programId);
+
+    await program.methods
+      .createWill(heir.publicKey, HEARTBEAT_PERIOD, referrer)
+      .accounts({ testator: owner.publicKey, will, vault })
+      .signers([owner])
+      .rpc();
+
+    await program.methods
+      .depositSol(new anchor.BN(depositLamports), false)
+      .accounts({ testator: owner.publicKey, will, vault })
+      .signers([owner])
+      .rpc();
+
+    return { owner, heir, will, vault };
+  };
+
+  const triggerWill = ({ will, vault }: any) =>
+    program.methods
+      .triggerWill()
+      .accounts({ keeper: keeper.publicKey, will, vault })
+      .signers([keeper])
+      .rpc();
+
+  const claimSol = ({ will, vault }: any, claimant: Keypair, referrer: PublicKey | null = null) =>
+    program.methods
+      .claimSol()
+      .accounts({ beneficiary: claimant.publicKey, will, destination: claimant.publicKey, vault, referrer })
+      .signers([claimant])
+      .rpc();
+
+  // Warps past the heartbeat period and trigger grace period, then triggers
+  const expireAndTrigger = async (will: any) => {
+    await warp(HEARTBEAT_PERIOD + TRIGGER_GRACE_PERIOD + 1);
+    await triggerWill(will);
+  };
+
+  before(async () => {
+    context = await startAnchor("", [], []);
+    provider = new BankrunProvider(context);
+    program = new Program<WasiatOnline>(IDL as WasiatOnline, provider);
+
+    authority = Keypair.generate();
+    keeper = Keypair.generate();
+    fund(authority, keeper);
+
+    [feeVaultPda] = PublicKey.findProgramAddressSync([Buffer.from("fee_vault")], program.programId);
+
+    await program.methods
+      .initialize(250, new anchor.BN(1_000_000), HEARTBEAT_PERIOD, 3 * 365 * 86_400, 60)
+      .accounts({ authority: authority.publicKey })
+      .signers([authority])
+      .rpc();
+
+    rent = Number((await context.banksClient.getRent()).minimumBalance(0n));
+  });
+
+  describe("Stake Accounts", () => {
+    it("should hand a deposited stake account to the beneficiary", async () => {
+      const { owner, heir, will, vault } = await createWill();
+      const stakeAccount = Keypair.generate();
+      await provider.sendAndConfirm(
+        StakeProgram.createAccount({
+          fromPubkey: owner.publicKey,
+          stakePubkey: stakeAccount.publicKey,
+          authorized: new Authorized(owner.publicKey, owner.publicKey),
+          lockup: new Lockup(0, 0, PublicKey.default),
+          lamports: LAMPORTS_PER_SOL / 2,
+        }),
+        [owner, stakeAccount]
+      );
+      await program.methods
+        .depositStake(false)
+        .accounts({ testator: owner.publicKey, will, stakeAccount: stakeAccount.publicKey, vault })
+        .signers([owner])
+        .rpc();
+
+      await expireAndTrigger({ will, vault });
+
+      await program.methods
+        .claimStake()
+        .accounts({
+          beneficiary: heir.publicKey,
+          will,
+          destination: heir.publicKey,
+          stakeAccount: stakeAccount.publicKey,
+          vault,
+          referrer: null,
+        })
+        .signers([heir])
+        .rpc();
+
+      // Meta.authorized follows the state tag and rent exempt reserve
+      const { data } = await context.banksClient.getAccount(stakeAccount.publicKey);
+      expect(new PublicKey(data.subarray(12, 44)).toString()).to.equal(heir.publicKey.toString());
+      expect(new PublicKey(data.subarray(44, 76)).toString()).to.equal(heir.publicKey.toString());
+      console.log("✓ Stake account authorities moved to beneficiary");
+    });
+  });
+
+  describe("Vault Stake", () => {
+    // Creates a vote account for a fresh validator identity
+    const createVoteAccount = async () => {
+      const node = Keypair.generate();
+      const voteAccount = Keypair.generate();
+      await provider.sendAndConfirm(
+        VoteProgram.createAccount({
+          fromPubkey: provider.wallet.publicKey,
+          votePubkey: voteAccount.publicKey,
+          voteInit: new VoteInit(node.publicKey, node.publicKey, node.publicKey, 0),
+          lamports: Number((await context.banksClient.getRent()).minimumBalance(BigInt(VoteProgram.space))),
+        }),
+        [node, voteAccount]
+      );
+      return voteAccount.publicKey;
+    };
+
+    it("should fail to claim SOL while vault SOL is staked", async () => {
+      const { owner, heir, will, vault } = await createWill(null, 2 * LAMPORTS_PER_SOL);
+      await program.methods
+        .stakeVaultSol(new anchor.BN(LAMPORTS_PER_SOL))
+        .accounts({
+          testator: owner.publicKey,
+          will,
+          vault,
+          voteAccount: await createVoteAccount(),
+          stakeConfig: STAKE_CONFIG_ID,
+        })
+        .signers([owner])
+        .rpc();
+
+      await expireAndTrigger({ will, vault });
+
+      await expectError(claimSol({ will, vault }, heir), "VaultStakeOutstanding");
+      console.log("✓ Claim while vault staked rejected");
+    });
+  });
+
+  describe("Contingent Beneficiary", () => {
+    const CLAIM_DEADLINE = 7 * 86_400; // 7 days
+
+    it("should let the contingent beneficiary claim after the deadline", async () => {
+      const contingentHeir = Keypair.generate();
+      fund(contingentHeir);
+      const { owner, heir, will, vault } = await createWill();
+      await program.methods
+        .setContingentBeneficiary(contingentHeir.publicKey, CLAIM_DEADLINE)
+        .accounts({ testator: owner.publicKey, will })
+        .signers([owner])
+        .rpc();
+
+      await expireAndTrigger({ will, vault });
+
+      await expectError(claimSol({ will, vault }, contingentHeir), "ClaimDeadlineNotReached");
+      console.log("✓ Contingent claim before deadline rejected");
+
+      await warp(CLAIM_DEADLINE);
+      const contingentBefore = await balance(contingentHeir.publicKey);
+
+      await claimSol({ will, vault }, contingentHeir);
+
+      expect(await balance(contingentHeir.publicKey)).to.be.greaterThan(contingentBefore);
+      const willAccount = await program.account.will.fetch(will);
+      expect(willAccount.claimedByContingent).to.be.true;
+      console.log("✓ Contingent beneficiary claimed");
+
+      await expectError(claimSol({ will, vault }, heir), "ClaimedByContingentBeneficiary");
+      console.log("✓ Primary beneficiary locked out after contingent claim");
+    });
+  });
+
+  describe("Fallback", () => {
+    const UNCLAIMED_EXPIRY = 30 * 86_400; // 30 days
+
+    const reclaimUnclaimedSol = ({ will, vault }: any, fallback: Keypair) =>
+      program.methods
+        .reclaimUnclaimedSol()
+        .accounts({ keeper: keeper.publicKey, will, vault, fallback: fallback.publicKey, referrer: null })
+        .signers([keeper])
+        .rpc();
+
+    it("should reclaim an unclaimed estate to the fallback", async () => {
+      const fallback = Keypair.generate();
+      fund(fallback);
+      const { owner, will, vault } = await createWill();
+      await program.methods
+        .setFallback(fallback.publicKey, UNCLAIMED_EXPIRY)
+        .accounts({ testator: owner.publicKey, will })
+        .signers([owner])
+        .rpc();
+
+      await expireAndTrigger({ will, vault });
+
+      await expectError(reclaimUnclaimedSol({ will, vault }, fallback), "EstateNotExpired");
+      console.log("✓ Reclaim within claim window rejected");
+
+      await warp(UNCLAIMED_EXPIRY);
+      const reclaimable = (await balance(vault)) - rent;
+      const fallbackBefore = await balance(fallback.publicKey);
+      const feeVaultBefore = await balance(feeVaultPda);
+
+      await reclaimUnclaimedSol({ will, vault }, fallback);
+
+      const fee = (await balance(feeVaultPda)) - feeVaultBefore;
+      expect((await balance(fallback.publicKey)) - fallbackBefore).to.equal(reclaimable - fee);
+      const willAccount = await program.account.will.fetch(will);
+      expect(willAccount.status).to.deep.equal({ expired: {} });
+      console.log("✓ Unclaimed estate reclaimed to fallback");
+    });
+  });
+
+  describe("Payout Schedule", () => {
+    const TRANCHE_INTERVAL = 86_400; // 1 day
+
+    it("should release SOL tranche by tranche", async () => {
+      const { owner, heir, will, vault } = await createWill();
+      await program.methods
+        .setPayoutSchedule({ tranches: { count: 2, interval: TRANCHE_INTERVAL } })
+        .accounts({ testator: owner.publicKey, will })
+        .signers([owner])
+        .rpc();
+
+      await expireAndTrigger({ will, vault });
+
+      const { solVestingTotal } = await program.account.will.fetch(will);
+      const vaultBefore = await balance(vault);
+
+      await claimSol({ will, vault }, heir);
+
+      const released = vaultBefore - (await balance(vault));
+      expect(released).to.equal(Math.floor(solVestingTotal.toNumber() / 2));
+      const willAccount = await program.account.will.fetch(will);
+      expect(willAccount.claimedSoFar.toNumber()).to.equal(released);
+      console.log("✓ First of two tranches released");
+
+      await expectError(claimSol({ will, vault }, heir), "NothingVested");
+      console.log("✓ Second tranche not yet vested");
+
+      await warp(TRANCHE_INTERVAL);
+      await claimSol({ will, vault }, heir);
+
+      expect(await balance(vault)).to.equal(rent);
+      console.log("✓ Second tranche released after its interval");
+    });
+  });
+
+  describe("Executor", () => {
+    const EXECUTOR_FEE_BPS = 500; // 5%
+
+    it("should distribute SOL through the executor", async () => {
+      const executor = Keypair.generate();
+      fund(executor);
+      const { owner, heir, will, vault } = await createWill();
+      await program.methods
+        .setExecutor(executor.publicKey, EXECUTOR_FEE_BPS)
+        .accounts({ testator: owner.publicKey, will })
+        .signers([owner])
+        .rpc();
+
+      await expireAndTrigger({ will, vault });
+
+      const distributable = (await balance(vault)) - rent;
+      const heirBefore = await balance(heir.publicKey);
+      const executorBefore = await balance(executor.publicKey);
+      const feeVaultBefore = await balance(feeVaultPda);
+
+      await program.methods
+        .distributeSol()
+        .accounts({ executor: executor.publicKey, will, beneficiary: heir.publicKey, vault, referrer: null })
+        .signers([executor])
+        .rpc();
+
+      const executorFee = Math.floor((distributable * EXECUTOR_FEE_BPS) / 10_000);
+      const fee = (await balance(feeVaultPda)) - feeVaultBefore;
+      expect((await balance(executor.publicKey)) - executorBefore).to.equal(executorFee);
+      expect((await balance(heir.publicKey)) - heirBefore).to.equal(distributable - fee - executorFee);
+      console.log("✓ Executor distributed SOL and received compensation");
+    });
+  });
+
+  describe("Claim All", () => {
+    const TOKEN_PROGRAM_ID = anchor.utils.token.TOKEN_PROGRAM_ID;
+    const ASSOCIATED_TOKEN_PROGRAM_ID = anchor.utils.token.ASSOCIATED_PROGRAM_ID;
+
+    // Creates an spl mint with `mintAuthority` (no freeze authority)
+    const createMint = async (mintAuthority: Keypair, decimals = 0) => {
+      const mint = Keypair.generate();
+      const data = Buffer.alloc(67);
+      data.writeUInt8(20, 0); // InitializeMint2
+      data.writeUInt8(decimals, 1);
+      mintAuthority.publicKey.toBuffer().copy(data, 2);
+      const tx = new anchor.web3.Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: mintAuthority.publicKey,
+          newAccountPubkey: mint.publicKey,
+          lamports: Number((await context.banksClient.getRent()).minimumBalance(82n)),
+          space: 82,
+          programId: TOKEN_PROGRAM_ID,
+        }),
+        new anchor.web3.TransactionInstruction({
+          programId: TOKEN_PROGRAM_ID,
+          keys: [{ pubkey: mint.publicKey, isSigner: false, isWritable: true }],
+          data,
+        })
+      );
+      await provider.sendAndConfirm(tx, [mintAuthority, mint]);
+      return mint.publicKey;
+    };
+
+    // Creates the associated token account of `owner` and mints `amount` to it
+    const mintToAta = async (mintAuthority: Keypair, mint: PublicKey, owner: PublicKey, amount: number) => {
+      const ata = anchor.utils.token.associatedAddress({ mint, owner });
+      const data = Buffer.alloc(9);
+      data.writeUInt8(7, 0); // MintTo
+      data.writeBigUInt64LE(BigInt(amount), 1);
+      const tx = new anchor.web3.Transaction().add(
+        new anchor.web3.TransactionInstruction({
+          programId: ASSOCIATED_TOKEN_PROGRAM_ID,
+          keys: [
+            { pubkey: mintAuthority.publicKey, isSigner: true, isWritable: true },
+            { pubkey: ata, isSigner: false, isWritable: true },
+            { pubkey: owner, isSigner: false, isWritable: false },
+            { pubkey: mint, isSigner: false, isWritable: false },
+            { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+            { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+          ],
+          data: Buffer.alloc(0),
+        }),
+        new anchor.web3.TransactionInstruction({
+          programId: TOKEN_PROGRAM_ID,
+          keys: [
+            { pubkey: mint, isSigner: false, isWritable: true },
+            { pubkey: ata, isSigner: false, isWritable: true },
+            { pubkey: mintAuthority.publicKey, isSigner: true, isWritable: false },
+          ],
+          data,
+        })
+      );
+      await provider.sendAndConfirm(tx, [mintAuthority]);
+      return ata;
+    };
+
+    // Token account amount follows the mint and owner keys
+    const tokenBalance = async (tokenAccount: PublicKey) => {
+      const { data } = await context.banksClient.getAccount(tokenAccount);
+      return Number(Buffer.from(data).readBigUInt64LE(64));
+    };
+
+    it("should claim SOL and tokens in one transaction", async () => {
+      const { owner, heir, will, vault } = await createWill();
+      const mint = await createMint(owner, 6);
+      const vaultTokenAccount = await mintToAta(owner, mint, vault, 1_000_000);
+
+      await expireAndTrigger({ will, vault });
+
+      const heirTokenAccount = anchor.utils.token.associatedAddress({ mint, owner: heir.publicKey });
+      const feeVaultTokenAccount = anchor.utils.token.associatedAddress({ mint, owner: feeVaultPda });
+
+      await program.methods
+        .claimAll()
+        .accounts({ beneficiary: heir.publicKey, will, destination: heir.publicKey, vault, referrer: null })
+        .remainingAccounts([
+          { pubkey: mint, isSigner: false, isWritable: false },
+          { pubkey: vaultTokenAccount, isSigner: false, isWritable: true },
+          { pubkey: heirTokenAccount, isSigner: false, isWritable: true },
+          { pubkey: feeVaultTokenAccount, isSigner: false, isWritable: true },
+        ])
+        .signers([heir])
+        .rpc();
+
+      const heirTokens = await tokenBalance(heirTokenAccount);
+      const feeTokens = await tokenBalance(feeVaultTokenAccount);
+      expect(heirTokens + feeTokens).to.equal(1_000_000);
+      expect(feeTokens).to.be.greaterThan(0);
+      expect(await balance(vault)).to.equal(rent);
+      const willAccount = await program.account.will.fetch(will);
+      expect(willAccount.status).to.deep.equal({ claimed: {} });
+      console.log("✓ Whole estate claimed");
+    });
+  });
+
+  describe("Expired Deposits", () => {
+    it("should reject deposits on an expired will awaiting trigger", async () => {
+      const { owner, will, vault } = await createWill();
+      await warp(HEARTBEAT_PERIOD + 1);
+
+      for (const asHeartbeat of [false, true]) {
+        await expectError(
+          program.methods
+            .depositSol(new anchor.BN(100_000_000), asHeartbeat)
+            .accounts({ testator: owner.publicKey, will, vault })
+            .signers([owner])
+            .rpc(),
+          "WillAlreadyExpired"
+        );
+      }
+      console.log("✓ Deposits on expired will rejected");
+    });
+  });
+
+  describe("Pause-Aware Expiry", () => {
+    const PAUSE_TRIGGER = 1 << 3;
+    const PAUSED_SECONDS = 3_600; // 1 hour
+
+    const setPauseFlags = (flags: number) =>
+      program.methods
+        .updateConfig(flags)
+        .accounts({ authority: authority.publicKey })
+        .signers([authority])
+        .rpc();
+
+    it("should extend will expiry by paused trigger time", async () => {
+      const { will, vault } = await createWill();
+
+      await setPauseFlags(PAUSE_TRIGGER);
+      await warp(PAUSED_SECONDS);
+      await setPauseFlags(0);
+
+      const config = await program.account.config.fetch(
+        PublicKey.findProgramAddressSync([Buffer.from("config")], program.programId)[0]
+      );
+      expect(config.totalPausedSeconds.toNumber()).to.equal(PAUSED_SECONDS);
+
+      // past the unpaused deadline, still within the paused time owed
+      await warp(HEARTBEAT_PERIOD + TRIGGER_GRACE_PERIOD + 1 - PAUSED_SECONDS);
+      await expectError(triggerWill({ will, vault }), "StillInGracePeriod");
+      console.log("✓ Trigger held back by paused time");
+
+      await warp(PAUSED_SECONDS);
+      await triggerWill({ will, vault });
+      const willAccount = await program.account.will.fetch(will);
+      expect(willAccount.status).to.deep.equal({ triggered: {} });
+      console.log("✓ Trigger allowed once paused time passed");
+    });
+  });
+
+  describe("Fee Snapshot", () => {
+    it("should claim SOL, charging the will fee", async () => {
+      const { heir, will, vault } = await createWill();
+
+      await expireAndTrigger({ will, vault });
+
+      const claimable = (await balance(vault)) - rent;
+      const heirBefore = await balance(heir.publicKey);
+      const feeVaultBefore = await balance(feeVaultPda);
+
+      await claimSol({ will, vault }, heir);
+
+      const fee = (await balance(feeVaultPda)) - feeVaultBefore;
+      const { feeSnapshot } = await program.account.will.fetch(will);
+      expect(fee).to.equal(Math.floor((claimable * feeSnapshot.tokenFeeBps) / 10_000));
+      expect((await balance(heir.publicKey)) - heirBefore).to.equal(claimable - fee);
+      expect(await balance(vault)).to.equal(rent);
+      console.log("✓ SOL claimed net of fee");
+    });
+  });
+
+  describe("Referrals", () => {
+    const REFERRER_SHARE_BPS = 2_000; // 20% of service fees
+    const CONFIG_CHANGE_DELAY = 7 * 86_400; // 7 days
+
+    before(async () => {
+      // flat schedule sharing fees with referrers, applied after the timelock
+      await program.methods
+        .queueFeeSchedule([], new anchor.BN(0), new anchor.BN(0), [], new anchor.BN(0), REFERRER_SHARE_BPS)
+        .accounts({ authority: authority.publicKey })
+        .signers([authority])
+        .rpc();
+      await warp(CONFIG_CHANGE_DELAY);
+      await program.methods
+        .executeFeeSchedule()
+        .accounts({ executor: keeper.publicKey, proposer: authority.publicKey })
+        .signers([keeper])
+        .rpc();
+    });
+
+    it("should pay the referrer its share of the claim fee", async () => {
+      const partner = Keypair.generate();
+      fund(partner);
+      const { heir, will, vault } = await createWill(partner.publicKey);
+
+      await expireAndTrigger({ will, vault });
+
+      await expectError(claimSol({ will, vault }, heir), "ReferrerMismatch");
+      console.log("✓ Claim without referrer rejected");
+
+      const claimable = (await balance(vault)) - rent;
+      const heirBefore = await balance(heir.publicKey);
+      const feeVaultBefore = await balance(feeVaultPda);
+      const partnerBefore = await balance(partner.publicKey);
+
+      await claimSol({ will, vault }, heir, partner.publicKey);
+
+      const protocolFee = (await balance(feeVaultPda)) - feeVaultBefore;
+      const referrerFee = (await balance(partner.publicKey)) - partnerBefore;
+      const fee = protocolFee + referrerFee;
+      expect(referrerFee).to.be.greaterThan(0);
+      expect(referrerFee).to.equal(Math.floor((fee * REFERRER_SHARE_BPS) / 10_000));
+      expect((await balance(heir.publicKey)) - heirBefore).to.equal(claimable - fee);
+      console.log(`✓ Fee split: ${protocolFee} protocol, ${referrerFee} referrer`);
+    });
+  });
+});
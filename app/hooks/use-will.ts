@@ -16,7 +16,9 @@ import {
   getWillPDA, 
   getAllWillPDAs,
   getGlobalConfigPDA,
-  getFeeVaultPDA 
+  getWillInventoryPDA,
+  getFeeVaultPDA,
+  getFeeSchedulePDA
 } from "../lib/anchor/pda";
 import { MIN_HEARTBEAT_PERIOD } from "../lib/utils/constants";
 import { getSOLBalance } from "../lib/solana/utils";
@@ -80,6 +82,7 @@ export function useWill(
         triggerAt: willData.triggerAt ? willData.triggerAt.toNumber() : null,
         bump: willData.bump,
         vaultBump: willData.vaultBump,
+        referrer: willData.referrer ?? null,
         reserved: Array.from(willData.reserved),
         // Computed properties
         isExpired,
@@ -130,14 +133,18 @@ export function useWill(
 
     try {
       const pdas = getAllWillPDAs(wallet.publicKey, params.beneficiary);
-      
+      // fee schedule fixes the referrer share at creation
+      const [feeSchedulePDA] = getFeeSchedulePDA();
+
       const tx = await program.methods
-        .createWill(params.beneficiary, params.heartbeatPeriod)
+        .createWill(params.beneficiary, params.heartbeatPeriod, params.referrer ?? null)
         .accounts({
           testator: wallet.publicKey,
           config: pdas.config,
           will: pdas.will,
           vault: pdas.vault,
+          feeSchedule: feeSchedulePDA,
+          willInventory: pdas.willInventory,
           systemProgram: SystemProgram.programId,
         } as any)
         .transaction();
@@ -177,11 +184,13 @@ export function useWill(
     
     try {
       const tx = await program.methods
-        .depositSol(new BN(lamports))
+        .depositSol(new BN(lamports), false)
         .accounts({
           testator: wallet.publicKey,
           will: will.address,
+          config: getGlobalConfigPDA()[0],
           vault: will.vault,
+          willInventory: getWillInventoryPDA(will.address)[0],
           systemProgram: SystemProgram.programId,
         } as any)
         .transaction();
@@ -218,6 +227,7 @@ export function useWill(
 
     try {
       const [configPDA] = getGlobalConfigPDA();
+      const [feeVaultPDA] = getFeeVaultPDA();
       
       const tx = await program.methods
         .sendHeartbeat()
@@ -225,6 +235,8 @@ export function useWill(
           testator: wallet.publicKey,
           config: configPDA,
           will: will?.address,
+          feeVault: feeVaultPDA,
+          systemProgram: SystemProgram.programId,
         } as any)
         .transaction();
 
@@ -258,15 +270,13 @@ export function useWill(
     }
 
     try {
-      const [configPDA] = getGlobalConfigPDA();
-      
       const tx = await program.methods
         .withdrawSol()
         .accounts({
           testator: wallet.publicKey,
           will: will.address,
           vault: will.vault,
-          config: configPDA,
+          willInventory: getWillInventoryPDA(will.address)[0],
           systemProgram: SystemProgram.programId,
         } as any)
         .transaction();
@@ -304,14 +314,19 @@ export function useWill(
     try {
       const [configPDA] = getGlobalConfigPDA();
       const [feeVaultPDA] = getFeeVaultPDA();
-      
+      const [feeSchedulePDA] = getFeeSchedulePDA();
+
       const tx = await program.methods
         .claimSol()
         .accounts({
           beneficiary: wallet.publicKey,
           will: will.address,
+          destination: wallet.publicKey,
           vault: will.vault,
+          willInventory: getWillInventoryPDA(will.address)[0],
           config: configPDA,
+          feeSchedule: feeSchedulePDA,
+          referrer: will.referrer,
           feeVault: feeVaultPDA,
           systemProgram: SystemProgram.programId,
         } as any)
@@ -51,6 +51,29 @@ export function getVaultPDA(willAccount: PublicKey): [PublicKey, number] {
   );
 }
 
+/**
+ * Derive Inventory PDA for a Will
+ */
+export function getWillInventoryPDA(willAccount: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(SEEDS.WILL_INVENTORY),
+      willAccount.toBuffer()
+    ],
+    new PublicKey(PROGRAM_ID)
+  );
+}
+
+/**
+ * Derive Fee Schedule PDA
+ */
+export function getFeeSchedulePDA(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEEDS.FEE_SCHEDULE)],
+    new PublicKey(PROGRAM_ID)
+  );
+}
+
 /**
  * Get all PDAs for a Will
  */
@@ -60,17 +83,20 @@ export function getAllWillPDAs(
 ) {
   const [will, willBump] = getWillPDA(testator, beneficiary);
   const [vault, vaultBump] = getVaultPDA(will);
+  const [willInventory, willInventoryBump] = getWillInventoryPDA(will);
   const [config, configBump] = getGlobalConfigPDA();
   const [feeVault, feeVaultBump] = getFeeVaultPDA();
   
   return {
     will,
     vault,
+    willInventory,
     config,
     feeVault,
     bumps: {
       will: willBump,
       vault: vaultBump,
+      willInventory: willInventoryBump,
       config: configBump,
       feeVault: feeVaultBump
     }
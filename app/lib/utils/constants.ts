@@ -41,6 +41,8 @@ export const SEEDS = {
   WILL: "will", 
   VAULT: "vault",
   FEE_VAULT: "fee_vault",
+  WILL_INVENTORY: "will_inventory",
+  FEE_SCHEDULE: "fee_schedule",
 } as const;
 
 // Default values
@@ -75,7 +75,7 @@ export function ProgramProvider({ children }: { children: ReactNode }) {
         minHeartbeatPeriod: configData.minHeartbeatPeriod,
         maxHeartbeatPeriod: configData.maxHeartbeatPeriod,
         minHeartbeatInterval: configData.minHeartbeatInterval,
-        paused: configData.paused,
+        pauseFlags: configData.pauseFlags,
         bump: configData.bump,
         reserved: Array.from(configData.reserved),
       };
@@ -21,6 +21,7 @@ export interface Will {
   triggerAt: number | null;
   bump: number;
   vaultBump: number;
+  referrer: PublicKey | null;
   reserved: number[];
 }
 
@@ -33,7 +34,7 @@ export interface Config {
   minHeartbeatPeriod: number;
   maxHeartbeatPeriod: number;
   minHeartbeatInterval: number;
-  paused: boolean;
+  pauseFlags: number;
   bump: number;
   reserved: number[];
 }
@@ -50,6 +51,7 @@ export interface PDAAccounts {
 export interface CreateWillParams {
   beneficiary: PublicKey;
   heartbeatPeriod: number;
+  referrer?: PublicKey;
 }
 
 // Will operations result
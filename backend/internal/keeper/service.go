@@ -17,8 +17,9 @@ import (
 
 type SolanaClient interface {
 	GetProgramAccounts(ctx context.Context) ([]*rpc.KeyedAccount, error)
-	TriggerWill(ctx context.Context, willPubkey solana.PublicKey) (string, error)
+	TriggerWill(ctx context.Context, willPubkey solana.PublicKey, migrate bool) (string, error)
 	GetSolanaTime(ctx context.Context) (int64, error)
+	GetConfig(ctx context.Context) (*types.Config, error)
 }
 
 type Service struct {
@@ -47,11 +48,25 @@ func (s *Service) ScanAndTriggerWills() error {
 
 	log.Printf("Found %d program accounts", len(accounts))
 
+	config, err := s.client.GetConfig(ctx)
+	if err != nil {
+		storage.LogKeeperAction(s.db, "scan", "", fmt.Sprintf("Failed to get config: %v", err), false, err.Error(), time.Since(startTime).Milliseconds())
+		return fmt.Errorf("failed to get config: %v", err)
+	}
+
+	// Wills cannot trigger while paused, and paused time extends every deadline
+	if config.IsPaused(types.PauseTrigger) {
+		storage.LogKeeperAction(s.db, "scan", "", "Trigger paused, skipping scan", true, "", time.Since(startTime).Milliseconds())
+		log.Printf("Trigger paused since %d, skipping scan", config.PausedSince)
+		return nil
+	}
+
 	triggeredCount := 0
 	errorCount := 0
 
 	for _, account := range accounts {
-		will, err := s.parseWillAccount(account.Account.Data.GetBinary())
+		data := account.Account.Data.GetBinary()
+		will, err := s.parseWillAccount(data)
 		if err != nil {
 			log.Printf("Failed to parse will account %s: %v", account.Pubkey, err)
 			errorCount++
@@ -71,7 +86,13 @@ func (s *Service) ScanAndTriggerWills() error {
 			errorCount++
 			continue
 		}
-		expiryTime := will.LastHeartbeat + int64(will.HeartbeatPeriod)
+
+		// Legacy wills get their pause baseline from migrate_will, sent along with the trigger
+		if len(data) == types.LegacyWillAccountSize {
+			will.PausedSecondsAtHeartbeat = config.MigratedPausedSecondsAtHeartbeat(will.LastHeartbeat, solanaTime)
+		}
+
+		expiryTime := will.ExpiryTime(config.PausedSeconds(solanaTime))
 		gracePeriod := int64(300) // 5 minutes (same as smart contract TRIGGER_GRACE_PERIOD)
 
 		log.Printf("Will %s: solanaTime=%d, lastHeartbeat=%d, period=%d, expiry=%d, withGrace=%d",
@@ -81,7 +102,7 @@ func (s *Service) ScanAndTriggerWills() error {
 		if solanaTime >= expiryTime+gracePeriod {
 			log.Printf("Will %s is expired, triggering...", account.Pubkey)
 
-			sig, err := s.client.TriggerWill(ctx, account.Pubkey)
+			sig, err := s.client.TriggerWill(ctx, account.Pubkey, len(data) == types.LegacyWillAccountSize)
 			if err != nil {
 				log.Printf("Failed to trigger will %s: %v", account.Pubkey, err)
 				storage.LogKeeperAction(s.db, "trigger", account.Pubkey.String(), fmt.Sprintf("Failed to trigger: %v", err), false, err.Error(), 0)
@@ -105,7 +126,7 @@ func (s *Service) ScanAndTriggerWills() error {
 }
 
 func (s *Service) parseWillAccount(data []byte) (*types.Will, error) {
-	if len(data) < 200 {
+	if len(data) < types.LegacyWillAccountSize {
 		return nil, fmt.Errorf("account data too short: %d bytes", len(data))
 	}
 
@@ -139,13 +160,61 @@ func (s *Service) parseWillAccount(data []byte) (*types.Will, error) {
 		will.TriggerAt = &triggerAt
 		offset += 8
 	} else {
-		offset += 9
+		offset += 1 // borsh writes None as a single tag byte
 	}
 
 	will.Bump = data[offset]
 	offset += 1
 
 	will.VaultBump = data[offset]
+	offset += 1
+
+	offset += 8 // staked_lamports
+	offset += 2 // action_count, next_action_index
+
+	// contingent_beneficiary, claim_deadline, claimed_by_contingent
+	if data[offset] == 1 {
+		offset += 33
+	} else {
+		offset += 1
+	}
+	offset += 5
+
+	// fallback, unclaimed_expiry
+	if data[offset] == 1 {
+		offset += 33
+	} else {
+		offset += 1
+	}
+	offset += 4
+
+	// payout_schedule (Immediate, Linear{u32, u32}, Tranches{u8, u32}), claimed_so_far, sol_vesting_total
+	switch data[offset] {
+	case 1:
+		offset += 9
+	case 2:
+		offset += 6
+	default:
+		offset += 1
+	}
+	offset += 16
+
+	// release_not_before
+	if data[offset] == 1 {
+		offset += 9
+	} else {
+		offset += 1
+	}
+
+	// executor, executor_fee_bps
+	if data[offset] == 1 {
+		offset += 33
+	} else {
+		offset += 1
+	}
+	offset += 2
+
+	will.PausedSecondsAtHeartbeat = binary.LittleEndian.Uint64(data[offset : offset+8])
 
 	return will, nil
 }
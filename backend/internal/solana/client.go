@@ -2,9 +2,12 @@ package solana
 
 import (
 	"context"
+	"encoding/binary"
 	"fmt"
 	"os"
 
+	"wasiat-online-backend/pkg/types"
+
 	"github.com/gagliardetto/solana-go"
 	"github.com/gagliardetto/solana-go/rpc"
 )
@@ -45,21 +48,32 @@ func NewClient() (*Client, error) {
 	}, nil
 }
 
+// GetProgramAccounts fetches will accounts, including legacy wills not yet migrated
 func (c *Client) GetProgramAccounts(ctx context.Context) ([]*rpc.KeyedAccount, error) {
-	return c.rpcClient.GetProgramAccountsWithOpts(
-		ctx,
-		c.programID,
-		&rpc.GetProgramAccountsOpts{
-			Filters: []rpc.RPCFilter{
-				{
-					DataSize: 200,
+	var accounts []*rpc.KeyedAccount
+	for _, size := range []uint64{types.WillAccountSize, types.LegacyWillAccountSize} {
+		sized, err := c.rpcClient.GetProgramAccountsWithOpts(
+			ctx,
+			c.programID,
+			&rpc.GetProgramAccountsOpts{
+				Filters: []rpc.RPCFilter{
+					{
+						DataSize: size,
+					},
 				},
 			},
-		},
-	)
+		)
+		if err != nil {
+			return nil, err
+		}
+		accounts = append(accounts, sized...)
+	}
+	return accounts, nil
 }
 
-func (c *Client) TriggerWill(ctx context.Context, willPubkey solana.PublicKey) (string, error) {
+// TriggerWill triggers an expired will, growing legacy wills with migrate_will first
+// (trigger_will cannot deserialize the legacy layout)
+func (c *Client) TriggerWill(ctx context.Context, willPubkey solana.PublicKey, migrate bool) (string, error) {
 	configPDA, _, err := solana.FindProgramAddress(
 		[][]byte{[]byte("config")},
 		c.programID,
@@ -68,9 +82,70 @@ func (c *Client) TriggerWill(ctx context.Context, willPubkey solana.PublicKey) (
 		return "", fmt.Errorf("failed to derive config PDA: %v", err)
 	}
 
+	vaultPDA, _, err := solana.FindProgramAddress(
+		[][]byte{[]byte("vault"), willPubkey.Bytes()},
+		c.programID,
+	)
+	if err != nil {
+		return "", fmt.Errorf("failed to derive vault PDA: %v", err)
+	}
+
 	// Create instruction data with correct discriminator from IDL
 	instructionData := []byte{50, 69, 144, 208, 215, 69, 29, 173}
 
+	header := solana.MessageHeader{
+		NumRequiredSignatures:       1,
+		NumReadonlySignedAccounts:   0,
+		NumReadonlyUnsignedAccounts: 3,
+	}
+	accountKeys := []solana.PublicKey{
+		c.keeper.PublicKey(), // signer
+		willPubkey,           // writable
+		configPDA,            // readonly
+		vaultPDA,             // readonly
+		c.programID,          // program
+	}
+	instructions := []solana.CompiledInstruction{
+		{
+			ProgramIDIndex: 4,
+			Accounts:       []uint16{0, 1, 2, 3},
+			Data:           instructionData,
+		},
+	}
+
+	if migrate {
+		willInventoryPDA, _, err := solana.FindProgramAddress(
+			[][]byte{[]byte("will_inventory"), willPubkey.Bytes()},
+			c.programID,
+		)
+		if err != nil {
+			return "", fmt.Errorf("failed to derive will inventory PDA: %v", err)
+		}
+
+		header.NumReadonlyUnsignedAccounts = 4
+		accountKeys = []solana.PublicKey{
+			c.keeper.PublicKey(),   // signer, pays migration rent
+			willPubkey,             // writable
+			willInventoryPDA,       // writable
+			configPDA,              // readonly
+			vaultPDA,               // readonly
+			solana.SystemProgramID, // readonly
+			c.programID,            // program
+		}
+		instructions = []solana.CompiledInstruction{
+			{
+				ProgramIDIndex: 6,
+				Accounts:       []uint16{0, 1, 2, 3, 5},
+				Data:           []byte{0, 97, 164, 197, 100, 164, 97, 229}, // migrate_will
+			},
+			{
+				ProgramIDIndex: 6,
+				Accounts:       []uint16{0, 1, 3, 4},
+				Data:           instructionData,
+			},
+		}
+	}
+
 	recent, err := c.rpcClient.GetLatestBlockhash(ctx, rpc.CommitmentFinalized)
 	if err != nil {
 		return "", fmt.Errorf("failed to get latest blockhash: %v", err)
@@ -79,25 +154,10 @@ func (c *Client) TriggerWill(ctx context.Context, willPubkey solana.PublicKey) (
 	// Create transaction with explicit signers
 	tx := &solana.Transaction{
 		Message: solana.Message{
-			Header: solana.MessageHeader{
-				NumRequiredSignatures:       1,
-				NumReadonlySignedAccounts:   0,
-				NumReadonlyUnsignedAccounts: 1,
-			},
-			AccountKeys: []solana.PublicKey{
-				c.keeper.PublicKey(), // signer
-				willPubkey,           // writable
-				configPDA,            // readonly
-				c.programID,          // program
-			},
+			Header:          header,
+			AccountKeys:     accountKeys,
 			RecentBlockhash: recent.Value.Blockhash,
-			Instructions: []solana.CompiledInstruction{
-				{
-					ProgramIDIndex: 3,
-					Accounts:       []uint16{0, 1, 2},
-					Data:           instructionData,
-				},
-			},
+			Instructions:    instructions,
 		},
 	}
 
@@ -131,6 +191,36 @@ func (c *Client) GetAccountInfo(ctx context.Context, pubkey solana.PublicKey) (*
 	return c.rpcClient.GetAccountInfo(ctx, pubkey)
 }
 
+// GetConfig fetches the program config and parses its pause accounting
+func (c *Client) GetConfig(ctx context.Context) (*types.Config, error) {
+	configPDA, _, err := solana.FindProgramAddress(
+		[][]byte{[]byte("config")},
+		c.programID,
+	)
+	if err != nil {
+		return nil, fmt.Errorf("failed to derive config PDA: %v", err)
+	}
+
+	configAccount, err := c.rpcClient.GetAccountInfo(ctx, configPDA)
+	if err != nil {
+		return nil, fmt.Errorf("failed to get config account: %v", err)
+	}
+
+	// discriminator, authority, fee_vault, token_fee_bps, nft_fee_lamports, heartbeat limits (94 bytes)
+	// then pause_flags, bump, paused_since, total_paused_seconds, last_pause_ended_at
+	if configAccount.Value == nil || len(configAccount.Value.Data.GetBinary()) < 120 {
+		return nil, fmt.Errorf("invalid config account data")
+	}
+	data := configAccount.Value.Data.GetBinary()
+
+	return &types.Config{
+		PauseFlags:         data[94],
+		PausedSince:        int64(binary.LittleEndian.Uint64(data[96:104])),
+		TotalPausedSeconds: binary.LittleEndian.Uint64(data[104:112]),
+		LastPauseEndedAt:   int64(binary.LittleEndian.Uint64(data[112:120])),
+	}, nil
+}
+
 func (c *Client) GetSolanaTime(ctx context.Context) (int64, error) {
 	return GetSolanaTime(ctx, c.rpcClient)
 }
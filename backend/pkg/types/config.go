@@ -0,0 +1,39 @@
+package types
+
+// PauseTrigger matches the program's PAUSE_TRIGGER flag
+const PauseTrigger uint8 = 1 << 3
+
+type Config struct {
+	PauseFlags         uint8
+	PausedSince        int64
+	TotalPausedSeconds uint64
+	LastPauseEndedAt   int64
+}
+
+// IsPaused reports whether any operation in flags is paused
+func (c *Config) IsPaused(flags uint8) bool {
+	return c.PauseFlags&flags != 0
+}
+
+// PausedSeconds returns the total trigger pause time, including an ongoing pause
+func (c *Config) PausedSeconds(now int64) uint64 {
+	ongoing := uint64(0)
+	if c.PausedSince > 0 && now > c.PausedSince {
+		ongoing = uint64(now - c.PausedSince)
+	}
+	return c.TotalPausedSeconds + ongoing
+}
+
+// MigratedPausedSecondsAtHeartbeat returns the pause baseline migrate_will gives a legacy will,
+// crediting only an ongoing pause since its last heartbeat
+func (c *Config) MigratedPausedSecondsAtHeartbeat(lastHeartbeat, now int64) uint64 {
+	since := c.PausedSince
+	if lastHeartbeat > since {
+		since = lastHeartbeat
+	}
+	ongoing := uint64(0)
+	if c.PausedSince > 0 && now > since {
+		ongoing = uint64(now - since)
+	}
+	return c.PausedSeconds(now) - ongoing
+}
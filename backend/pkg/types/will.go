@@ -10,6 +10,14 @@ const (
 	WillStatusTriggered WillStatus = 2
 	WillStatusClaimed   WillStatus = 3
 	WillStatusWithdrawn WillStatus = 4
+	WillStatusExpired   WillStatus = 5
+)
+
+const (
+	// WillAccountSize is the will account size (8 + Will::INIT_SPACE)
+	WillAccountSize = 456
+	// LegacyWillAccountSize is the size of wills created before migrate_will existed
+	LegacyWillAccountSize = 200
 )
 
 type Will struct {
@@ -23,4 +31,15 @@ type Will struct {
 	TriggerAt       *int64
 	Bump            uint8
 	VaultBump       uint8
+
+	PausedSecondsAtHeartbeat uint64
+}
+
+// ExpiryTime returns when the heartbeat runs out, extended by trigger pauses since the last heartbeat
+func (w *Will) ExpiryTime(pausedSeconds uint64) int64 {
+	pausedSinceHeartbeat := uint64(0)
+	if pausedSeconds > w.PausedSecondsAtHeartbeat {
+		pausedSinceHeartbeat = pausedSeconds - w.PausedSecondsAtHeartbeat
+	}
+	return w.LastHeartbeat + int64(w.HeartbeatPeriod) + int64(pausedSinceHeartbeat)
 }
\ No newline at end of file